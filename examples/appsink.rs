@@ -14,7 +14,7 @@ fn main(){
 	let appsink = pipeline.get_by_name("appsink0").expect("Couldn't get appsink from pipeline");
 	let appsink = gst::AppSink::new_from_element(appsink);
 	mainloop.spawn();
-	pipeline.play();
+	pipeline.play().expect("Couldn't set pipeline to playing");
 	Command::new("tput").args(&["civis","--","invisible"]).status().unwrap();
 	thread::spawn(move||{
 		loop {
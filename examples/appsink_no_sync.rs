@@ -19,7 +19,7 @@ fn main(){
 	let bus_receiver = bus.receiver();
     let mut mainloop = gst::MainLoop::new();
     mainloop.spawn();
-    playbin.play();
+    playbin.play().expect("Couldn't set playbin to playing");
 	loop {
         let mut exit = false;
         while let Ok(msg) = bus_receiver.try_recv(){
@@ -55,7 +55,6 @@ fn main(){
         }
     }
 
-    playbin.set_null_state();
-    playbin.get_state(gst::ffi::GST_CLOCK_TIME_NONE);
+    playbin.set_state_blocking(gst::State::Null, gst::ClockTime::NONE).expect("Couldn't stop playbin");
 	mainloop.quit();
 }
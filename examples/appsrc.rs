@@ -20,7 +20,7 @@ fn main(){
 	    panic!("Couldn't activate buffer pool");
 	}
 	mainloop.spawn();
-	pipeline.play();
+	pipeline.play().expect("Couldn't set pipeline to playing");
 
 	thread::spawn(move||{
 	    let condvar = Condvar::new();
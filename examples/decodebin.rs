@@ -1,21 +1,6 @@
 extern crate gst;
 
 use std::env;
-use std::os::raw::c_void;
-use std::mem;
-
-extern "C" fn signal_callback(_src: *mut gst::ffi::GstElement, pad: *mut gst::ffi::GstPad, sinkpad: &mut gst::Pad){
-    unsafe{
-        if !sinkpad.is_linked(){
-            let mut decodebin_pad = gst::Pad::new(pad).unwrap();
-            let caps = decodebin_pad.query_caps(None).unwrap();
-            let structure = caps.structure(0).unwrap();
-            if structure.name().starts_with("video") {
-                decodebin_pad.link(sinkpad).unwrap();
-            }
-        }
-    }
-}
 
 fn main(){
     gst::init();
@@ -30,11 +15,8 @@ fn main(){
     let mut filesrc = gst::Element::new("filesrc", "").unwrap();
     filesrc.set("location", uri);
     let mut decodebin = gst::Element::new("decodebin", "").unwrap();
-    let mut sink = gst::Element::new("glimagesink", "").unwrap();
-    let mut sink_pad = sink.static_pad("sink").unwrap();
-    unsafe{
-        decodebin.signal_connect("pad-added", mem::transmute(signal_callback as *mut c_void), &mut sink_pad);
-    }
+    let sink = gst::Element::new("glimagesink", "").unwrap();
+    decodebin.auto_link(&sink, "video");
     if !pipeline.add_and_link(filesrc, decodebin){
         panic!("couldn't link filesrc and decodebin");
     }
@@ -43,7 +25,7 @@ fn main(){
     let mut bus = pipeline.bus().expect("Couldn't get pipeline bus");
     let bus_receiver = bus.receiver();
     mainloop.spawn();
-    pipeline.play();
+    pipeline.play().expect("Couldn't set pipeline to playing");
     for message in bus_receiver.iter(){
         match message.parse(){
             gst::Message::StateChangedParsed{ref old, ref new, ..} => {
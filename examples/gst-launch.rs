@@ -10,7 +10,7 @@ fn main(){
 	let mut bus = pipeline.bus().expect("Couldn't get bus from pipeline");
 	let bus_receiver = bus.receiver();
 	mainloop.spawn();
-	pipeline.play();
+	pipeline.play().expect("Couldn't set pipeline to playing");
 	for message in bus_receiver.iter(){
 		match message.parse(){
 			gst::Message::StateChangedParsed{ref old, ref new, ..} => {
@@ -16,7 +16,7 @@ fn main(){
     let mut bus = playbin.bus().expect("Couldn't get pipeline bus");
     let bus_receiver = bus.receiver();
     mainloop.spawn();
-    playbin.play();
+    playbin.play().expect("Couldn't set playbin to playing");
     for message in bus_receiver.iter(){
         match message.parse(){
             gst::Message::StateChangedParsed{ref old, ref new, ..} => {
@@ -2,6 +2,7 @@ use ffi::*;
 use ::Transfer;
 use ::Element;
 use ::Caps;
+use ::ClockTime;
 use std::mem;
 use reference::Reference;
 
@@ -48,12 +49,21 @@ impl AppSrc{
 	    }
     }
 
-    pub fn latency(&self) -> (u64,u64){
+    /// Retrieve the min and max latencies, max is NONE when unlimited.
+    pub fn latency(&self) -> (ClockTime,ClockTime){
         unsafe{
             let mut min: u64 = 0;
             let mut max: u64 = 0;
             gst_app_src_get_latency(mem::transmute(self.gst_appsrc()), &mut min, &mut max);
-            (min,max)
+            (ClockTime::from_gst_clock_time(min), ClockTime::from_gst_clock_time(max))
+        }
+    }
+
+    /// Configure the min and max latency. If max is NONE, the latency is
+    /// unlimited.
+    pub fn set_latency(&mut self, min: ClockTime, max: ClockTime){
+        unsafe{
+            gst_app_src_set_latency(self.gst_appsrc_mut(), min.to_gst_clock_time(), max.to_gst_clock_time());
         }
     }
 
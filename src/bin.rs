@@ -1,9 +1,12 @@
 use ffi::*;
 use element::Element;
+use ghost_pad::GhostPad;
+use pad::Pad;
 use util::*;
 use iterator::Iter;
 use ::Transfer;
 use reference::Reference;
+use object::SignalHandlerId;
 
 use std::ops::{Deref, DerefMut};
 
@@ -202,6 +205,39 @@ impl Bin{
         }
     }
 
+    /// Gets an iterator for all elements in the bin that have the
+    /// GST_ELEMENT_FLAG_SINK flag set.
+    pub fn iter_sinks(&self) -> Iter<Element>{
+        unsafe{
+            Iter::new_from_gst_iterator(gst_bin_iterate_sinks(self.gst_bin() as *mut GstBin)).unwrap()
+        }
+    }
+
+    /// Gets an iterator for all elements in the bin that have the
+    /// GST_ELEMENT_FLAG_SOURCE flag set.
+    pub fn iter_sources(&self) -> Iter<Element>{
+        unsafe{
+            Iter::new_from_gst_iterator(gst_bin_iterate_sources(self.gst_bin() as *mut GstBin)).unwrap()
+        }
+    }
+
+    /// Gets an iterator for the elements in this bin in topologically
+    /// sorted order. This means that the elements are returned from the
+    /// most downstream elements (sinks) to the sources.
+    pub fn iter_sorted(&self) -> Iter<Element>{
+        unsafe{
+            Iter::new_from_gst_iterator(gst_bin_iterate_sorted(self.gst_bin() as *mut GstBin)).unwrap()
+        }
+    }
+
+    /// Looks for all elements inside the bin that implement the given
+    /// interface, recursing into child bins.
+    pub fn iter_all_by_interface(&self, iface: GType) -> Iter<Element>{
+        unsafe{
+            Iter::new_from_gst_iterator(gst_bin_iterate_all_by_interface(self.gst_bin() as *mut GstBin, iface)).unwrap()
+        }
+    }
+
     /// Query bin for the current latency using and reconfigures this latency
     /// to all the elements with a LATENCY event.
 	///
@@ -235,6 +271,73 @@ impl Bin{
         self.set("message-forward", forward);
     }
 
+    /// Recursively looks for elements with an unlinked pad of the given
+    /// direction within the bin and returns an unlinked pad if one is
+    /// found, or None otherwise.
+    pub fn find_unlinked_pad(&self, direction: GstPadDirection) -> Option<Pad>{
+        unsafe{
+            Pad::new(gst_bin_find_unlinked_pad(self.gst_bin() as *mut GstBin, direction))
+        }
+    }
+
+    /// Exposes every unlinked pad of the elements in this bin as a ghost
+    /// pad on the bin itself, so the bin can be linked like any other
+    /// element. Source pads are named "src", "src_1", ... and sink pads
+    /// "sink", "sink_1", ...
+    ///
+    /// This should be called once all the children have been added and
+    /// linked to each other.
+    ///
+    /// Returns the ghost pads that were added to the bin.
+    pub fn ghost_unlinked_pads(&mut self) -> Vec<GhostPad>{
+        let mut ghost_pads = Vec::new();
+        for &(direction, prefix) in [(GST_PAD_SRC, "src"), (GST_PAD_SINK, "sink")].iter(){
+            let mut idx = 0;
+            while let Some(target) = self.find_unlinked_pad(direction){
+                let name = if idx == 0 {
+                    prefix.to_string()
+                } else {
+                    format!("{}_{}", prefix, idx)
+                };
+                idx += 1;
+                match GhostPad::new(&name, &target){
+                    Some(ghost_pad) => {
+                        if !self.add_pad(&ghost_pad){
+                            break;
+                        }
+                        ghost_pads.push(ghost_pad);
+                    }
+                    None => break
+                }
+            }
+        }
+        ghost_pads
+    }
+
+    /// Calls callback every time an element is added to the bin. The
+    /// callback receives the new child element.
+    pub fn on_element_added<F>(&mut self, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&Element) + Send + Sync + 'static{
+        self.connect("element-added", move |args| {
+            if let Some(element) = args.get(1).and_then(|arg| arg.get::<Element>()){
+                callback(&element);
+            }
+            None
+        })
+    }
+
+    /// Calls callback every time an element is removed from the bin. The
+    /// callback receives the removed child element.
+    pub fn on_element_removed<F>(&mut self, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&Element) + Send + Sync + 'static{
+        self.connect("element-removed", move |args| {
+            if let Some(element) = args.get(1).and_then(|arg| arg.get::<Element>()){
+                callback(&element);
+            }
+            None
+        })
+    }
+
     /// Returns a const raw pointer to the internal GstElement
     pub unsafe fn gst_bin(&self) -> *const GstBin{
         self.bin.gst_element() as *const GstBin
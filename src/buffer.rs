@@ -1,8 +1,11 @@
 use ffi::*;
 use reference::Reference;
 use miniobject::MiniObject;
+use clock_time::ClockTime;
+use value;
 
 use std::mem;
+use std::ptr;
 use std::fmt::{Debug, Formatter, Error};
 use std::ops::{Deref, DerefMut};
 
@@ -95,6 +98,41 @@ impl Buffer{
         self.buffer.gst_miniobject_mut() as *mut GstBuffer
     }
 
+    /// The presentation timestamp of the buffer, the time at which the
+    /// buffer content should be presented to the user. NONE when the
+    /// timestamp is not known or relevant.
+    pub fn pts(&self) -> ClockTime{
+        unsafe{ ClockTime::from_gst_clock_time((*self.gst_buffer()).pts) }
+    }
+
+    /// Sets the presentation timestamp of the buffer
+    pub fn set_pts(&mut self, pts: ClockTime){
+        unsafe{ (*self.gst_buffer_mut()).pts = pts.to_gst_clock_time() }
+    }
+
+    /// The decoding timestamp of the buffer, the time at which the buffer
+    /// content should be decoded. NONE when the timestamp is not known or
+    /// relevant.
+    pub fn dts(&self) -> ClockTime{
+        unsafe{ ClockTime::from_gst_clock_time((*self.gst_buffer()).dts) }
+    }
+
+    /// Sets the decoding timestamp of the buffer
+    pub fn set_dts(&mut self, dts: ClockTime){
+        unsafe{ (*self.gst_buffer_mut()).dts = dts.to_gst_clock_time() }
+    }
+
+    /// The duration of the data in the buffer, NONE when the duration is
+    /// not known or relevant.
+    pub fn duration(&self) -> ClockTime{
+        unsafe{ ClockTime::from_gst_clock_time((*self.gst_buffer()).duration) }
+    }
+
+    /// Sets the duration of the buffer
+    pub fn set_duration(&mut self, duration: ClockTime){
+        unsafe{ (*self.gst_buffer_mut()).duration = duration.to_gst_clock_time() }
+    }
+
     pub fn flags(&self) -> guint {
         unsafe { (*self.gst_buffer()).mini_object.flags }
     }
@@ -132,6 +170,31 @@ macro_rules! fmt_buffer_flag {
     )
 }
 
+impl ::FromGValue for Buffer{
+    fn from_gvalue(gvalue: &GValue) -> Option<Buffer>{
+        unsafe{
+            if value::holds(gvalue, gst_buffer_get_type()){
+                let buffer = g_value_get_boxed(gvalue) as *mut GstMiniObject;
+                if buffer != ptr::null_mut(){
+                    Buffer::new(gst_mini_object_ref(buffer) as *mut GstBuffer)
+                }else{
+                    None
+                }
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ::ToGValue for Buffer{
+    fn to_gvalue(&self) -> GValue{
+        let mut gvalue = value::new_gvalue(unsafe{ gst_buffer_get_type() });
+        unsafe{ g_value_set_boxed(&mut gvalue, self.gst_buffer() as gconstpointer) };
+        gvalue
+    }
+}
+
 impl Debug for Buffer {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         try!(fmt.write_fmt(format_args!("GstBuffer<{} bytes", self.size())));
@@ -1,13 +1,17 @@
 use ffi::*;
 
 use std::sync::mpsc::{self,channel,Receiver};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use message::Message;
 use util::*;
 use reference::Reference;
-use object::Object;
+use object::{Object, SignalHandlerId};
+use clock_time::ClockTime;
+use ::Transfer;
 
-static REMOVE_WATCH_MESSAGE_STR: &'static str = "gstreamer1.0-rs_remove_watch_message";
+static DISPATCHER_KEY: &'static str = "gstreamer1.0-rs_bus_dispatcher";
 
 unsafe impl Sync for Bus {}
 unsafe impl Send for Bus {}
@@ -21,20 +25,238 @@ impl Bus{
         Object::new(bus as *mut GstObject).map(|obj| Bus{bus: obj})
     }
 
-    pub fn add_watch<W: Watch>(&mut self, watch: W) -> u32{
+    /// Adds a watch to the bus that will be called with every message
+    /// posted on it until it returns false or `remove_watch` is called.
+    /// Watches are called from the thread running the default main loop.
+    ///
+    /// Returns an id for the watch.
+    pub fn add_watch<W: Watch + 'static>(&mut self, watch: W) -> u32{
+        let mut watch = watch;
+        self.dispatcher().add(GST_MESSAGE_ANY, None, true, Box::new(move |msg: &Message| watch.call(msg.reference())))
+    }
+
+    /// Removes all the watches added with `add_watch`. Subscriptions
+    /// created with `subscribe` are not affected.
+    ///
+    /// Returns false if there were no watches on the bus.
+    pub fn remove_watch(&mut self) -> bool{
+        self.dispatcher().remove_watches()
+    }
+
+    /// Subscribes to the messages posted on the bus whose type matches
+    /// the message type mask types and, if src is given, that were posted
+    /// by that object. Any number of subscriptions can be active on the
+    /// same bus at the same time.
+    ///
+    /// The callback is called from the thread running the default main
+    /// loop until the returned Subscription is dropped.
+    ///
+    /// ```ignore
+    /// let _errors = bus.subscribe(GST_MESSAGE_ERROR | GST_MESSAGE_WARNING, None, |msg| {
+    ///     println!("{:?}", msg.type_name());
+    /// });
+    /// ```
+    pub fn subscribe<F>(&mut self, types: GstMessageType, src: Option<&Object>, callback: F) -> Subscription
+        where F: FnMut(&Message) + Send + 'static{
+        let mut callback = callback;
+        let dispatcher = self.dispatcher();
+        let id = dispatcher.add(types, src.map(|src| src.reference()), false, Box::new(move |msg: &Message| { callback(msg); true }));
+        Subscription{ dispatcher: dispatcher, id: id }
+    }
+
+    fn dispatcher(&mut self) -> Arc<Dispatcher>{
+        let ckey = CString::new(DISPATCHER_KEY).unwrap();
+        let bus = unsafe{ self.gst_bus_mut() };
+        let mut dispatcher = None;
+        self.bus.lock(|obj| unsafe{
+            let object = obj.gst_object_mut() as *mut GObject;
+            let data = g_object_get_data(object, ckey.as_ptr()) as *const Dispatcher;
+            if data == ptr::null(){
+                let new_dispatcher = Arc::new(Dispatcher::new(bus));
+                let data = Arc::into_raw(new_dispatcher.clone()) as gpointer;
+                g_object_set_data_full(object, ckey.as_ptr(), data, Some(dispatcher_destroy));
+                dispatcher = Some(new_dispatcher);
+            }else{
+                let existing = Arc::from_raw(data);
+                dispatcher = Some(existing.clone());
+                mem::forget(existing);
+            }
+        });
+        dispatcher.unwrap()
+    }
+
+    /// Post a message on the bus. This function takes ownership of the
+    /// message.
+    ///
+    /// Returns false if the bus is flushing.
+    pub fn post(&mut self, message: Message) -> bool{
         unsafe{
-            let watch: Box<Watch> = Box::new(watch);
-            let watch: *mut Box<Watch> = Box::into_raw(Box::new(watch));
-            gst_bus_add_watch (self.gst_bus_mut(), Some(bus_callback), mem::transmute(watch))
+            gst_bus_post(self.gst_bus_mut(), message.transfer()) != 0
         }
     }
 
-    pub fn remove_watch(&mut self) -> bool{
+    /// Gets a message from the bus, waiting up to the specified timeout.
+    ///
+    /// If timeout is ClockTime::ZERO, this function behaves like `pop`. If
+    /// timeout is ClockTime::NONE, this function will block forever until
+    /// a message was posted on the bus.
+    ///
+    /// Returns None if the timeout expired or the bus is flushing.
+    pub fn timed_pop<T: Into<ClockTime>>(&mut self, timeout: T) -> Option<Message>{
+        unsafe{
+            Message::new_from_gst_message(gst_bus_timed_pop(self.gst_bus_mut(), timeout.into().into()))
+        }
+    }
+
+    /// Gets a message from the bus whose type matches the message type
+    /// mask types, waiting up to the specified timeout and discarding any
+    /// messages that do not match the mask provided.
+    ///
+    /// ```ignore
+    /// let message = bus.timed_pop_filtered(ClockTime::NONE, GST_MESSAGE_EOS | GST_MESSAGE_ERROR);
+    /// ```
+    pub fn timed_pop_filtered<T: Into<ClockTime>>(&mut self, timeout: T, types: GstMessageType) -> Option<Message>{
+        unsafe{
+            Message::new_from_gst_message(gst_bus_timed_pop_filtered(self.gst_bus_mut(), timeout.into().into(), types))
+        }
+    }
+
+    /// Gets a message from the bus without waiting, or None if the bus
+    /// is empty.
+    pub fn pop(&mut self) -> Option<Message>{
+        unsafe{
+            Message::new_from_gst_message(gst_bus_pop(self.gst_bus_mut()))
+        }
+    }
+
+    /// Gets a message matching types from the bus without waiting. Will
+    /// discard all messages on the bus that do not match types.
+    pub fn pop_filtered(&mut self, types: GstMessageType) -> Option<Message>{
+        unsafe{
+            Message::new_from_gst_message(gst_bus_pop_filtered(self.gst_bus_mut(), types))
+        }
+    }
+
+    /// Peeks the message on the top of the bus' queue. The message will
+    /// remain on the bus' message queue.
+    pub fn peek(&self) -> Option<Message>{
+        unsafe{
+            Message::new_from_gst_message(gst_bus_peek(self.gst_bus() as *mut GstBus))
+        }
+    }
+
+    /// Checks if there are pending messages on the bus that should be
+    /// handled.
+    pub fn have_pending(&self) -> bool{
         unsafe{
-            let message_cstr = CString::new(REMOVE_WATCH_MESSAGE_STR).unwrap();
-            let structure = gst_structure_new(message_cstr.as_ptr(), ptr::null());
-            let message = gst_message_new_application(ptr::null_mut(), structure);
-            gst_bus_post(self.gst_bus_mut(), message) != 0
+            gst_bus_have_pending(self.gst_bus() as *mut GstBus) != 0
+        }
+    }
+
+    /// If flushing is true, the bus will flush out any queued messages,
+    /// as well as any future messages, until the function is called with
+    /// flushing set to false.
+    pub fn set_flushing(&mut self, flushing: bool){
+        unsafe{
+            gst_bus_set_flushing(self.gst_bus_mut(), flushing as gboolean);
+        }
+    }
+
+    /// Returns an iterator over the messages on the bus. Each call to
+    /// next waits up to timeout for a message and the iteration ends when
+    /// the timeout expires. With ClockTime::NONE the iterator blocks until
+    /// a message arrives, so it never ends unless the bus is flushing.
+    ///
+    /// ```ignore
+    /// for message in bus.iter_timed(ClockTime::NONE){
+    ///     match message.parse(){
+    ///         Message::ErrorParsed{ref error, ..} => { println!("{}", error); break; }
+    ///         Message::Eos(_) => break,
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn iter_timed<'a, T: Into<ClockTime>>(&'a mut self, timeout: T) -> TimedIter<'a>{
+        TimedIter{
+            bus: self,
+            timeout: timeout.into(),
+        }
+    }
+
+    /// Sets the synchronous handler on the bus. The handler will be called
+    /// every time a new message is posted on the bus, from the thread that
+    /// posted the message, so it must be careful not to block.
+    ///
+    /// The returned BusSyncReply decides what happens with the message:
+    /// it can be dropped, passed to the async queue to be handled by the
+    /// watches or by popping it, or handled asynchronously.
+    ///
+    /// Any previously installed sync handler is removed and dropped first.
+    /// The handler can be called from several streaming threads at the
+    /// same time, so it has to be Sync.
+    ///
+    /// ```ignore
+    /// bus.set_sync_handler(|message| {
+    ///     if let Message::NeedContext(_) = *message{
+    ///         // answer the context request here
+    ///         BusSyncReply::Drop
+    ///     }else{
+    ///         BusSyncReply::Pass
+    ///     }
+    /// });
+    /// ```
+    pub fn set_sync_handler<F>(&mut self, handler: F)
+        where F: Fn(&Message) -> BusSyncReply + Send + Sync + 'static{
+        self.unset_sync_handler();
+        unsafe{
+            let handler: Box<SyncHandler> = Box::new(handler);
+            let handler: *mut Box<SyncHandler> = Box::into_raw(Box::new(handler));
+            gst_bus_set_sync_handler(self.gst_bus_mut(), Some(sync_handler_callback), handler as gpointer, Some(sync_handler_destroy));
+        }
+    }
+
+    /// Calls callback for messages posted on the bus, from the thread that
+    /// posted the message. Unlike the sync handler, any number of these
+    /// callbacks can be connected and they can't drop the message
+    /// themselves.
+    ///
+    /// The callbacks run after the sync handler set with
+    /// `set_sync_handler`, and only if it didn't return
+    /// `BusSyncReply::Drop`: a sync handler dropping a message suppresses
+    /// every `on_sync_message` callback for it.
+    pub fn on_sync_message<F>(&mut self, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&Message) + Send + Sync + 'static{
+        let id = self.bus.connect("sync-message", move |args| {
+            if let Some(arg) = args.get(1){
+                unsafe{
+                    if let Some(message) = Message::new(g_value_get_boxed(arg.gvalue()) as *const GstMessage){
+                        callback(&message);
+                    }
+                }
+            }
+            None
+        });
+        if id.is_some(){
+            unsafe{
+                gst_bus_enable_sync_message_emission(self.gst_bus_mut());
+            }
+        }
+        id
+    }
+
+    /// Disconnects a callback connected with `on_sync_message`, dropping
+    /// it.
+    pub fn remove_sync_message_handler(&mut self, id: SignalHandlerId){
+        self.bus.disconnect(id);
+        unsafe{
+            gst_bus_disable_sync_message_emission(self.gst_bus_mut());
+        }
+    }
+
+    /// Removes the synchronous handler from the bus, dropping it.
+    pub fn unset_sync_handler(&mut self){
+        unsafe{
+            gst_bus_set_sync_handler(self.gst_bus_mut(), None, ptr::null_mut(), None);
         }
     }
 
@@ -53,28 +275,203 @@ impl Bus{
     }
 }
 
-extern "C" fn bus_callback(_bus: *mut GstBus, msg: *mut GstMessage, data: gpointer) -> gboolean {
-    unsafe{
-        let alive = {
-            let mut watch: &mut Box<Watch> = mem::transmute(data);
-            match Message::new(msg){
-                Some(Message::Application(app_msg)) => {
-                    let structure = gst_message_get_structure(app_msg);
-                    let cname = gst_structure_get_name(structure);
-                    if from_c_str!(cname) == REMOVE_WATCH_MESSAGE_STR{
-                        false
-                    }else{
-                        watch.call(Message::Application(app_msg))
-                    }
-                }
-    			Some(msg) => watch.call(msg),
-    			None => true,
+/// Iterator over the messages of a Bus, see `Bus::iter_timed`. Messages
+/// of types not known to these bindings are returned as
+/// `Message::Unknown`, the iterator only ends when no message arrives
+/// within the timeout.
+pub struct TimedIter<'a>{
+    bus: &'a mut Bus,
+    timeout: ClockTime,
+}
+
+impl<'a> Iterator for TimedIter<'a>{
+    type Item = Message;
+
+    fn next(&mut self) -> Option<Message>{
+        self.bus.timed_pop(self.timeout)
+    }
+}
+
+type DispatcherCallback = Box<dyn FnMut(&Message) -> bool + Send>;
+
+struct Subscriber{
+    id: u32,
+    types: GstMessageType,
+    src: Option<Object>,
+    is_watch: bool,
+    active: Arc<AtomicBool>,
+    callback: Arc<Mutex<DispatcherCallback>>,
+}
+
+struct DispatcherState{
+    next_id: u32,
+    watch_id: u32,
+    subscribers: Vec<Subscriber>,
+}
+
+/// Shares the single GStreamer watch of a bus between all the watches
+/// and subscriptions on it. It's stored as data on the GstBus so every
+/// Bus referencing the same GstBus uses the same dispatcher.
+struct Dispatcher{
+    bus: *mut GstBus,
+    state: Mutex<DispatcherState>,
+}
+
+unsafe impl Send for Dispatcher {}
+unsafe impl Sync for Dispatcher {}
+
+impl Dispatcher{
+    fn new(bus: *mut GstBus) -> Dispatcher{
+        Dispatcher{
+            bus: bus,
+            state: Mutex::new(DispatcherState{
+                next_id: 1,
+                watch_id: 0,
+                subscribers: vec![],
+            })
+        }
+    }
+
+    fn add(self: &Arc<Self>, types: GstMessageType, src: Option<Object>, is_watch: bool, callback: DispatcherCallback) -> u32{
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.subscribers.push(Subscriber{
+            id: id,
+            types: types,
+            src: src,
+            is_watch: is_watch,
+            active: Arc::new(AtomicBool::new(true)),
+            callback: Arc::new(Mutex::new(callback)),
+        });
+        if state.watch_id == 0{
+            unsafe{
+                let data = Arc::into_raw(self.clone()) as gpointer;
+                state.watch_id = gst_bus_add_watch_full(self.bus, 0, Some(dispatcher_callback), data, Some(dispatcher_destroy));
             }
+        }
+        id
+    }
+
+    fn remove(&self, id: u32){
+        let mut state = self.state.lock().unwrap();
+        for subscriber in state.subscribers.iter().filter(|s| s.id == id){
+            subscriber.active.store(false, Ordering::SeqCst);
+        }
+        state.subscribers.retain(|s| s.id != id);
+        self.remove_source_if_unused(&mut state);
+    }
+
+    fn remove_watches(&self) -> bool{
+        let mut state = self.state.lock().unwrap();
+        let len = state.subscribers.len();
+        for subscriber in state.subscribers.iter().filter(|s| s.is_watch){
+            subscriber.active.store(false, Ordering::SeqCst);
+        }
+        state.subscribers.retain(|s| !s.is_watch);
+        let removed = state.subscribers.len() != len;
+        self.remove_source_if_unused(&mut state);
+        removed
+    }
+
+    fn remove_source_if_unused(&self, state: &mut DispatcherState){
+        if state.subscribers.is_empty() && state.watch_id != 0{
+            unsafe{ g_source_remove(state.watch_id) };
+            state.watch_id = 0;
+        }
+    }
+
+    fn dispatch(&self, msg: &Message){
+        let ty = msg.ty();
+        let src = unsafe{ msg.gst_src() };
+        let matching: Vec<(u32, Arc<AtomicBool>, Arc<Mutex<DispatcherCallback>>)> = {
+            let state = self.state.lock().unwrap();
+            state.subscribers.iter()
+                .filter(|s| s.types & ty != 0)
+                .filter(|s| s.src.as_ref().map(|obj| unsafe{ obj.gst_object() } == src as *const GstObject).unwrap_or(true))
+                .map(|s| (s.id, s.active.clone(), s.callback.clone()))
+                .collect()
         };
-        if !alive{
-            Box::from_raw(data);
+        for (id, active, callback) in matching{
+            let mut callback = callback.lock().unwrap();
+            if active.load(Ordering::SeqCst) && !(*callback)(msg){
+                self.remove(id);
+            }
         }
-        if alive {1} else {0}
+    }
+}
+
+extern "C" fn dispatcher_callback(_bus: *mut GstBus, msg: *mut GstMessage, data: gpointer) -> gboolean{
+    unsafe{
+        let dispatcher = &*(data as *const Dispatcher);
+        if let Some(msg) = Message::new(msg){
+            dispatcher.dispatch(&msg);
+        }
+        1
+    }
+}
+
+extern "C" fn dispatcher_destroy(data: gpointer){
+    unsafe{
+        drop(Arc::from_raw(data as *const Dispatcher));
+    }
+}
+
+/// Handle for a subscription to the messages of a Bus, see
+/// `Bus::subscribe`. The subscription ends when it's dropped, after
+/// which the callback won't be called anymore.
+pub struct Subscription{
+    dispatcher: Arc<Dispatcher>,
+    id: u32,
+}
+
+impl Subscription{
+    pub fn id(&self) -> u32{
+        self.id
+    }
+}
+
+impl Drop for Subscription{
+    fn drop(&mut self){
+        self.dispatcher.remove(self.id);
+    }
+}
+
+/// The result values for a bus sync handler.
+#[repr(u32)]
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum BusSyncReply{
+    /// Drop the message
+    Drop = GST_BUS_DROP,
+    /// Pass the message to the async queue
+    Pass = GST_BUS_PASS,
+    /// Pass message to async queue, continue if message is handled
+    Async = GST_BUS_ASYNC,
+}
+
+trait SyncHandler: Send + Sync{
+    fn call(&self, msg: &Message) -> BusSyncReply;
+}
+
+impl<F: Fn(&Message) -> BusSyncReply + Send + Sync> SyncHandler for F{
+    fn call(&self, msg: &Message) -> BusSyncReply{
+        self(msg)
+    }
+}
+
+extern "C" fn sync_handler_callback(_bus: *mut GstBus, msg: *mut GstMessage, data: gpointer) -> GstBusSyncReply{
+    unsafe{
+        let handler = &*(data as *const Box<SyncHandler>);
+        match Message::new(msg){
+            Some(msg) => handler.call(&msg) as GstBusSyncReply,
+            None => GST_BUS_PASS,
+        }
+    }
+}
+
+extern "C" fn sync_handler_destroy(data: gpointer){
+    unsafe{
+        drop(Box::from_raw(data as *mut Box<SyncHandler>));
     }
 }
 
@@ -4,10 +4,13 @@ use util::*;
 use std::ops::{Deref, DerefMut};
 
 use structure::Structure;
+use caps_features::CapsFeatures;
 use reference::Reference;
 use object::{Property, FromProperty};
 use miniobject::MiniObject;
 use object::Object;
+use ::Transfer;
+use value;
 
 #[derive(Clone)]
 pub struct Caps{
@@ -77,12 +80,249 @@ impl Caps{
 		self.caps.gst_miniobject_mut() as *mut GstCaps
 	}
 
+	/// Returns a copy of the structure at index or None if the index is
+	/// out of range. The copy is detached from caps, modifying it doesn't
+	/// change caps.
 	pub fn structure(&self, index: u32) -> Option<Structure>{
 		unsafe{
 			let structure = gst_caps_get_structure(self.gst_caps(), index);
-			Structure::new_from_gst_structure(structure)
+			if structure != ptr::null_mut(){
+				Structure::new_from_gst_structure(gst_structure_copy(structure))
+			}else{
+				None
+			}
+		}
+	}
+
+	/// Returns a copy of the features of the structure at index or None
+	/// if the index is out of range. Structures without explicit features
+	/// have the `memory:SystemMemory` feature.
+	pub fn features(&self, index: u32) -> Option<CapsFeatures>{
+		if index >= self.size(){
+			return None;
+		}
+		unsafe{
+			let features = gst_caps_get_features(self.gst_caps(), index);
+			if features != ptr::null_mut(){
+				CapsFeatures::new_from_gst_caps_features(gst_caps_features_copy(features))
+			}else{
+				None
+			}
+		}
+	}
+
+	/// Sets the features of the structure at index. self is made writable
+	/// first if needed.
+	///
+	/// Returns the features back as an error if index is out of range.
+	pub fn set_features(&mut self, index: u32, features: CapsFeatures) -> Result<(), CapsFeatures>{
+		if index >= self.size(){
+			return Err(features);
+		}
+		unsafe{
+			self.make_writable_in_place();
+			gst_caps_set_features(self.gst_caps_mut(), index, features.transfer());
+		}
+		Ok(())
+	}
+
+	/// Returns true if every structure in caps only uses normal system
+	/// memory. Empty and ANY caps return false.
+	pub fn is_system_memory(&self) -> bool{
+		!self.is_any() && !self.is_empty() && (0..self.size()).all(|i| self.features(i).map(|f| f.is_system_memory()).unwrap_or(false))
+	}
+
+	/// Returns an iterator over copies of the structures in caps
+	pub fn structures<'a>(&'a self) -> Structures<'a>{
+		Structures{
+			caps: self,
+			idx: 0,
+			size: self.size(),
+		}
+	}
+
+	/// Gets the number of structures contained in caps.
+	pub fn size(&self) -> u32{
+		unsafe{
+			gst_caps_get_size(self.gst_caps())
+		}
+	}
+
+	/// Determines if caps represents no media formats.
+	pub fn is_empty(&self) -> bool{
+		unsafe{
+			gst_caps_is_empty(self.gst_caps()) != 0
+		}
+	}
+
+	/// Determines if caps represents any media format.
+	pub fn is_any(&self) -> bool{
+		unsafe{
+			gst_caps_is_any(self.gst_caps()) != 0
+		}
+	}
+
+	/// Fixed caps describe exactly one format, that is, they have exactly
+	/// one structure, and each field in the structure describes a fixed type.
+	/// Examples of non-fixed types are ranges and lists.
+	pub fn is_fixed(&self) -> bool{
+		unsafe{
+			gst_caps_is_fixed(self.gst_caps()) != 0
+		}
+	}
+
+	/// Checks if all caps represented by self are also represented by
+	/// superset.
+	pub fn is_subset(&self, superset: &Caps) -> bool{
+		unsafe{
+			gst_caps_is_subset(self.gst_caps(), superset.gst_caps()) != 0
+		}
+	}
+
+	/// A given caps structure is always compatible with another if every
+	/// media format that is in the first is also contained in the second.
+	/// That is, self is a subset of other.
+	pub fn is_always_compatible(&self, other: &Caps) -> bool{
+		unsafe{
+			gst_caps_is_always_compatible(self.gst_caps(), other.gst_caps()) != 0
+		}
+	}
+
+	/// Checks if the given caps are exactly the same set of caps, including
+	/// the order of the structures.
+	pub fn is_strictly_equal(&self, other: &Caps) -> bool{
+		unsafe{
+			gst_caps_is_strictly_equal(self.gst_caps(), other.gst_caps()) != 0
+		}
+	}
+
+	/// Tries intersecting self and other and reports whether the result
+	/// would not be empty
+	pub fn can_intersect(&self, other: &Caps) -> bool{
+		unsafe{
+			gst_caps_can_intersect(self.gst_caps(), other.gst_caps()) != 0
+		}
+	}
+
+	/// Creates new caps that contain all the formats that are common to
+	/// both self and other. Defaults to GST_CAPS_INTERSECT_ZIG_ZAG mode.
+	pub fn intersect(&self, other: &Caps) -> Caps{
+		unsafe{
+			Caps::new(gst_caps_intersect(self.gst_caps() as *mut GstCaps, other.gst_caps() as *mut GstCaps)).unwrap()
+		}
+	}
+
+	/// Creates new caps that contain all the formats that are common to
+	/// both self and other, the order is defined by the GstCapsIntersectMode
+	/// used.
+	///
+	/// With GST_CAPS_INTERSECT_FIRST the order of self is preserved and
+	/// is the preferred one.
+	pub fn intersect_full(&self, other: &Caps, mode: GstCapsIntersectMode) -> Caps{
+		unsafe{
+			Caps::new(gst_caps_intersect_full(self.gst_caps() as *mut GstCaps, other.gst_caps() as *mut GstCaps, mode)).unwrap()
 		}
 	}
+
+	/// Subtracts subtrahend from self.
+	pub fn subtract(&self, subtrahend: &Caps) -> Caps{
+		unsafe{
+			Caps::new(gst_caps_subtract(self.gst_caps() as *mut GstCaps, subtrahend.gst_caps() as *mut GstCaps)).unwrap()
+		}
+	}
+
+	/// Returns a writable version of caps, copying it if it's shared.
+	pub fn make_writable(self) -> Caps{
+		Caps{ caps: self.caps.make_writable() }
+	}
+
+	/// Appends the structures contained in other to self. The structures
+	/// in other are not copied -- they are transferred to self. self is
+	/// made writable first if needed.
+	pub fn append(&mut self, other: Caps){
+		unsafe{
+			self.make_writable_in_place();
+			gst_caps_append(self.gst_caps_mut(), other.transfer());
+		}
+	}
+
+	/// Appends structure to self. self is made writable first if needed.
+	pub fn append_structure(&mut self, structure: Structure){
+		unsafe{
+			self.make_writable_in_place();
+			gst_caps_append_structure(self.gst_caps_mut(), structure.transfer());
+		}
+	}
+
+	/// Appends structure with features to self. self is made writable
+	/// first if needed.
+	pub fn append_structure_full(&mut self, structure: Structure, features: Option<CapsFeatures>){
+		unsafe{
+			self.make_writable_in_place();
+			let features = features.map(|f| f.transfer()).unwrap_or(ptr::null_mut());
+			gst_caps_append_structure_full(self.gst_caps_mut(), structure.transfer(), features);
+		}
+	}
+
+	/// Removes the structure with the given index from the list of
+	/// structures contained in caps. self is made writable first if needed.
+	pub fn remove_structure(&mut self, index: u32){
+		if index < self.size(){
+			unsafe{
+				self.make_writable_in_place();
+				gst_caps_remove_structure(self.gst_caps_mut(), index);
+			}
+		}
+	}
+
+	/// Appends the structures contained in other to self if they are not
+	/// yet expressed by self and returns the merged caps.
+	pub fn merge(self, other: Caps) -> Caps{
+		unsafe{
+			Caps::new(gst_caps_merge(self.transfer(), other.transfer())).unwrap()
+		}
+	}
+
+	/// Modifies the caps so that they are fixed: all but the first
+	/// structure are discarded and every field is fixated to its nearest
+	/// fixed value.
+	pub fn fixate(self) -> Caps{
+		unsafe{
+			Caps::new(gst_caps_fixate(self.transfer())).unwrap()
+		}
+	}
+
+	/// Discards all but the first structure from caps. Useful when fixating.
+	pub fn truncate(self) -> Caps{
+		unsafe{
+			Caps::new(gst_caps_truncate(self.transfer())).unwrap()
+		}
+	}
+
+	/// Converts the given caps into a representation that represents the
+	/// same set of formats, but in a simpler form. Component structures
+	/// that are identical are merged. Component structures that have values
+	/// that can be merged are also merged.
+	///
+	/// This method does not preserve the original order of caps.
+	pub fn simplify(self) -> Caps{
+		unsafe{
+			Caps::new(gst_caps_simplify(self.transfer())).unwrap()
+		}
+	}
+
+	/// Returns caps that represent the same set of formats as self, but
+	/// contain no lists. Each list is expanded into separate structures.
+	pub fn normalize(self) -> Caps{
+		unsafe{
+			Caps::new(gst_caps_normalize(self.transfer())).unwrap()
+		}
+	}
+
+	unsafe fn make_writable_in_place(&mut self){
+		let caps = ptr::read(&self.caps);
+		ptr::write(&mut self.caps, caps.make_writable());
+	}
 }
 
 
@@ -142,6 +382,52 @@ impl PartialEq for Caps{
 
 impl Eq for Caps{}
 
+/// Iterator over the structures of Caps, see `Caps::structures`
+pub struct Structures<'a>{
+	caps: &'a Caps,
+	idx: u32,
+	size: u32,
+}
+
+impl<'a> Iterator for Structures<'a>{
+	type Item = Structure;
+
+	fn next(&mut self) -> Option<Structure>{
+		if self.idx < self.size{
+			let structure = self.caps.structure(self.idx);
+			self.idx += 1;
+			structure
+		}else{
+			None
+		}
+	}
+}
+
+impl ::FromGValue for Caps{
+    fn from_gvalue(gvalue: &GValue) -> Option<Caps>{
+        unsafe{
+            if value::holds(gvalue, gst_caps_get_type()){
+                let caps = g_value_get_boxed(gvalue) as *mut GstMiniObject;
+                if caps != ptr::null_mut(){
+                    Caps::new(gst_mini_object_ref(caps) as *mut GstCaps)
+                }else{
+                    None
+                }
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ::ToGValue for Caps{
+    fn to_gvalue(&self) -> GValue{
+        let mut gvalue = value::new_gvalue(unsafe{ gst_caps_get_type() });
+        unsafe{ g_value_set_boxed(&mut gvalue, self.gst_caps() as gconstpointer) };
+        gvalue
+    }
+}
+
 
 impl AsRef<MiniObject> for Caps{
     fn as_ref(&self) -> &MiniObject{
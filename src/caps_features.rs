@@ -0,0 +1,248 @@
+use ffi::*;
+use util::*;
+use value;
+
+use std::fmt::{self, Debug, Formatter};
+
+use ::{FromGValue, ToGValue};
+
+/// Feature for caps backed by normal system memory
+pub const CAPS_FEATURE_MEMORY_SYSTEM_MEMORY: &'static str = "memory:SystemMemory";
+/// Feature for video caps that can carry an overlay composition meta
+pub const CAPS_FEATURE_META_GST_VIDEO_OVERLAY_COMPOSITION: &'static str = "meta:GstVideoOverlayComposition";
+
+unsafe impl Send for CapsFeatures {}
+
+/// CapsFeatures can optionally be set on each structure of Caps to
+/// describe additional features of the media format, for example a
+/// specific memory type or which metas are supported. Structures without
+/// features are implicitly `memory:SystemMemory`.
+///
+/// In the string representation of caps the features are written in
+/// parenthesis after the media type:
+///
+/// ```ignore
+/// video/x-raw(memory:GLMemory, meta:GstVideoOverlayComposition), format=RGBA
+/// ```
+///
+/// A CapsFeatures owns the underlying GstCapsFeatures and frees it when
+/// dropped. Features obtained from caps are copies of the original.
+pub struct CapsFeatures{
+    features: *mut GstCapsFeatures,
+}
+
+impl Drop for CapsFeatures{
+    fn drop(&mut self){
+        unsafe{
+            gst_caps_features_free(self.features);
+        }
+    }
+}
+
+impl CapsFeatures{
+    /// Creates CapsFeatures from a raw pointer, taking ownership of it.
+    /// The GstCapsFeatures must not have a parent.
+    pub unsafe fn new_from_gst_caps_features(features: *mut GstCapsFeatures) -> Option<CapsFeatures>{
+        if features != ptr::null_mut(){
+            Some(CapsFeatures{
+                features: features
+            })
+        }else{
+            None
+        }
+    }
+
+    /// Creates new, empty CapsFeatures.
+    pub fn new_empty() -> CapsFeatures{
+        unsafe{
+            CapsFeatures{ features: gst_caps_features_new_empty() }
+        }
+    }
+
+    /// Creates new, ANY CapsFeatures. This will be equal to any other
+    /// CapsFeatures but caps with these are unfixed.
+    pub fn new_any() -> CapsFeatures{
+        unsafe{
+            CapsFeatures{ features: gst_caps_features_new_any() }
+        }
+    }
+
+    /// Creates new CapsFeatures with the given features.
+    pub fn new(features: &[&str]) -> CapsFeatures{
+        let mut caps_features = CapsFeatures::new_empty();
+        for feature in features{
+            caps_features.add(feature);
+        }
+        caps_features
+    }
+
+    /// Creates CapsFeatures from a string representation like
+    /// "memory:SystemMemory, meta:GstVideoOverlayComposition".
+    pub fn from_string(string: &str) -> Option<CapsFeatures>{
+        let cstring = CString::new(string).unwrap();
+        unsafe{
+            CapsFeatures::new_from_gst_caps_features(gst_caps_features_from_string(cstring.as_ptr()))
+        }
+    }
+
+    /// Converts features to a string representation.
+    pub fn to_string(&self) -> String{
+        unsafe{
+            let cstring = gst_caps_features_to_string(self.features);
+            let string = from_c_str!(cstring).to_string();
+            g_free(cstring as gpointer);
+            string
+        }
+    }
+
+    /// Returns the number of features.
+    pub fn size(&self) -> u32{
+        unsafe{
+            gst_caps_features_get_size(self.features)
+        }
+    }
+
+    /// Returns the feature at index or None if the index is out of range.
+    pub fn nth(&self, index: u32) -> Option<&str>{
+        unsafe{
+            let feature = gst_caps_features_get_nth(self.features, index);
+            if feature != ptr::null(){
+                Some(from_c_str!(feature))
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Returns an iterator over the features.
+    pub fn iter<'a>(&'a self) -> Iter<'a>{
+        Iter{
+            features: self,
+            idx: 0,
+            size: self.size(),
+        }
+    }
+
+    /// Check if features contains feature.
+    pub fn contains(&self, feature: &str) -> bool{
+        let cfeature = CString::new(feature).unwrap();
+        unsafe{
+            gst_caps_features_contains(self.features, cfeature.as_ptr()) != 0
+        }
+    }
+
+    /// Returns true if these are the ANY features.
+    pub fn is_any(&self) -> bool{
+        unsafe{
+            gst_caps_features_is_any(self.features) != 0
+        }
+    }
+
+    /// Returns true if these features only describe normal system memory.
+    /// Empty and ANY features return false.
+    pub fn is_system_memory(&self) -> bool{
+        !self.is_any() && self.size() > 0 && self.iter().all(|feature| feature == CAPS_FEATURE_MEMORY_SYSTEM_MEMORY)
+    }
+
+    /// Adds feature to features.
+    pub fn add(&mut self, feature: &str){
+        let cfeature = CString::new(feature).unwrap();
+        unsafe{
+            gst_caps_features_add(self.features, cfeature.as_ptr());
+        }
+    }
+
+    /// Removes feature from features.
+    pub fn remove(&mut self, feature: &str){
+        let cfeature = CString::new(feature).unwrap();
+        unsafe{
+            gst_caps_features_remove(self.features, cfeature.as_ptr());
+        }
+    }
+
+    pub unsafe fn gst_caps_features(&self) -> *const GstCapsFeatures{
+        self.features
+    }
+
+    pub unsafe fn gst_caps_features_mut(&mut self) -> *mut GstCapsFeatures{
+        self.features
+    }
+}
+
+impl Clone for CapsFeatures{
+    fn clone(&self) -> CapsFeatures{
+        unsafe{
+            CapsFeatures{ features: gst_caps_features_copy(self.features) }
+        }
+    }
+}
+
+impl PartialEq for CapsFeatures{
+    fn eq(&self, other: &CapsFeatures) -> bool{
+        unsafe{
+            gst_caps_features_is_equal(self.features, other.features) != 0
+        }
+    }
+}
+
+impl Eq for CapsFeatures{}
+
+impl Debug for CapsFeatures{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>{
+        fmt.write_str(&self.to_string())
+    }
+}
+
+impl ::Transfer<GstCapsFeatures> for CapsFeatures{
+    unsafe fn transfer(self) -> *mut GstCapsFeatures{
+        let features = self.features;
+        mem::forget(self);
+        features
+    }
+}
+
+impl FromGValue for CapsFeatures{
+    fn from_gvalue(gvalue: &GValue) -> Option<CapsFeatures>{
+        unsafe{
+            if value::holds(gvalue, gst_caps_features_get_type()){
+                let features = g_value_get_boxed(gvalue) as *const GstCapsFeatures;
+                if features != ptr::null(){
+                    CapsFeatures::new_from_gst_caps_features(gst_caps_features_copy(features))
+                }else{
+                    None
+                }
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for CapsFeatures{
+    fn to_gvalue(&self) -> GValue{
+        let mut gvalue = value::new_gvalue(unsafe{ gst_caps_features_get_type() });
+        unsafe{ g_value_set_boxed(&mut gvalue, self.features as gconstpointer) };
+        gvalue
+    }
+}
+
+/// Iterator over the features of CapsFeatures
+pub struct Iter<'a>{
+    features: &'a CapsFeatures,
+    idx: u32,
+    size: u32,
+}
+
+impl<'a> Iterator for Iter<'a>{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str>{
+        if self.idx < self.size{
+            let feature = self.features.nth(self.idx);
+            self.idx += 1;
+            feature
+        }else{
+            None
+        }
+    }
+}
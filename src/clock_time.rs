@@ -0,0 +1,325 @@
+use ffi::*;
+
+use std::fmt::{self, Display, Formatter};
+use std::error;
+use std::ops::{Add, Sub, Mul, Div};
+use std::str::FromStr;
+use std::time::Duration;
+
+const NSECONDS_PER_SECOND: u64 = 1000000000;
+
+/// A time in nanoseconds as used in GStreamer for timestamps, durations,
+/// positions and latencies. Unlike the raw GstClockTime the invalid time
+/// GST_CLOCK_TIME_NONE is represented as None so it can't be mistaken
+/// for a valid value.
+///
+/// Arithmetic between ClockTimes returns ClockTime::NONE if any of the
+/// operands is NONE, if the result overflows or underflows or on division
+/// by zero, use the checked variants to tell those cases apart. The same
+/// applies to the constructors, a time that doesn't fit into a
+/// GstClockTime becomes NONE.
+///
+/// ClockTimes are displayed in the same h:mm:ss.nnnnnnnnn format used by
+/// GStreamer, that can also be parsed back with `str::parse`.
+///
+/// ```ignore
+/// let position = ClockTime::from_seconds(90) + 500 * ClockTime::MSECOND;
+/// assert_eq!(position.to_string(), "0:01:30.500000000");
+/// assert_eq!("0:01:30.5".parse::<ClockTime>(), Ok(position));
+/// ```
+#[derive(Copy,Clone,Debug,PartialEq,Eq,PartialOrd,Ord,Hash,Default)]
+pub struct ClockTime(Option<u64>);
+
+impl ClockTime{
+    /// The invalid time, GST_CLOCK_TIME_NONE
+    pub const NONE: ClockTime = ClockTime(None);
+    /// Zero time
+    pub const ZERO: ClockTime = ClockTime(Some(0));
+    /// One second
+    pub const SECOND: ClockTime = ClockTime(Some(NSECONDS_PER_SECOND));
+    /// One millisecond
+    pub const MSECOND: ClockTime = ClockTime(Some(1000000));
+    /// One microsecond
+    pub const USECOND: ClockTime = ClockTime(Some(1000));
+    /// One nanosecond
+    pub const NSECOND: ClockTime = ClockTime(Some(1));
+
+    /// Creates a ClockTime from nanoseconds, u64::MAX is
+    /// GST_CLOCK_TIME_NONE and becomes ClockTime::NONE
+    pub fn from_nseconds(nseconds: u64) -> ClockTime{
+        ClockTime::from_gst_clock_time(nseconds)
+    }
+
+    /// Creates a ClockTime from microseconds, NONE on overflow
+    pub fn from_useconds(useconds: u64) -> ClockTime{
+        ClockTime::from_checked(useconds.checked_mul(1000))
+    }
+
+    /// Creates a ClockTime from milliseconds, NONE on overflow
+    pub fn from_mseconds(mseconds: u64) -> ClockTime{
+        ClockTime::from_checked(mseconds.checked_mul(1000000))
+    }
+
+    /// Creates a ClockTime from seconds, NONE on overflow
+    pub fn from_seconds(seconds: u64) -> ClockTime{
+        ClockTime::from_checked(seconds.checked_mul(NSECONDS_PER_SECOND))
+    }
+
+    /// Creates a ClockTime from a raw GstClockTime, GST_CLOCK_TIME_NONE
+    /// becomes ClockTime::NONE
+    pub fn from_gst_clock_time(time: GstClockTime) -> ClockTime{
+        if time == GST_CLOCK_TIME_NONE{
+            ClockTime::NONE
+        }else{
+            ClockTime(Some(time))
+        }
+    }
+
+    fn from_checked(nseconds: Option<u64>) -> ClockTime{
+        nseconds.map(ClockTime::from_nseconds).unwrap_or(ClockTime::NONE)
+    }
+
+    fn valid(nseconds: u64) -> Option<ClockTime>{
+        if nseconds != GST_CLOCK_TIME_NONE{
+            Some(ClockTime(Some(nseconds)))
+        }else{
+            None
+        }
+    }
+
+    /// Returns the raw GstClockTime, GST_CLOCK_TIME_NONE for
+    /// ClockTime::NONE
+    pub fn to_gst_clock_time(&self) -> GstClockTime{
+        self.0.unwrap_or(GST_CLOCK_TIME_NONE)
+    }
+
+    /// Returns true if the time is valid
+    pub fn is_some(&self) -> bool{
+        self.0.is_some()
+    }
+
+    /// Returns true if the time is ClockTime::NONE
+    pub fn is_none(&self) -> bool{
+        self.0.is_none()
+    }
+
+    /// The time in nanoseconds
+    pub fn nseconds(&self) -> Option<u64>{
+        self.0
+    }
+
+    /// The time in whole microseconds
+    pub fn useconds(&self) -> Option<u64>{
+        self.0.map(|ns| ns / 1000)
+    }
+
+    /// The time in whole milliseconds
+    pub fn mseconds(&self) -> Option<u64>{
+        self.0.map(|ns| ns / 1000000)
+    }
+
+    /// The time in whole seconds
+    pub fn seconds(&self) -> Option<u64>{
+        self.0.map(|ns| ns / NSECONDS_PER_SECOND)
+    }
+
+    /// The time in seconds as a floating point value
+    pub fn seconds_f64(&self) -> Option<f64>{
+        self.0.map(|ns| ns as f64 / NSECONDS_PER_SECOND as f64)
+    }
+
+    /// Converts to a Duration, None for ClockTime::NONE
+    pub fn to_duration(&self) -> Option<Duration>{
+        self.0.map(|ns| Duration::new(ns / NSECONDS_PER_SECOND, (ns % NSECONDS_PER_SECOND) as u32))
+    }
+
+    /// Adds two times, None if any of them is NONE or on overflow
+    pub fn checked_add(self, other: ClockTime) -> Option<ClockTime>{
+        match (self.0, other.0){
+            (Some(a), Some(b)) => a.checked_add(b).and_then(ClockTime::valid),
+            _ => None
+        }
+    }
+
+    /// Subtracts two times, None if any of them is NONE or if other is
+    /// bigger than self
+    pub fn checked_sub(self, other: ClockTime) -> Option<ClockTime>{
+        match (self.0, other.0){
+            (Some(a), Some(b)) => a.checked_sub(b).and_then(ClockTime::valid),
+            _ => None
+        }
+    }
+
+    /// Multiplies the time, None if it is NONE or on overflow
+    pub fn checked_mul(self, factor: u64) -> Option<ClockTime>{
+        self.0.and_then(|ns| ns.checked_mul(factor)).and_then(ClockTime::valid)
+    }
+
+    /// Divides the time, None if it is NONE or if divisor is 0
+    pub fn checked_div(self, divisor: u64) -> Option<ClockTime>{
+        self.0.and_then(|ns| ns.checked_div(divisor)).and_then(ClockTime::valid)
+    }
+}
+
+impl From<GstClockTime> for ClockTime{
+    fn from(time: GstClockTime) -> ClockTime{
+        ClockTime::from_gst_clock_time(time)
+    }
+}
+
+impl From<ClockTime> for GstClockTime{
+    fn from(time: ClockTime) -> GstClockTime{
+        time.to_gst_clock_time()
+    }
+}
+
+/// Signed representation as used for seeking and positions, -1 for NONE
+impl From<ClockTime> for i64{
+    fn from(time: ClockTime) -> i64{
+        time.0.map(|ns| ns as i64).unwrap_or(-1)
+    }
+}
+
+impl From<ClockTime> for Option<u64>{
+    fn from(time: ClockTime) -> Option<u64>{
+        time.0
+    }
+}
+
+/// None and values equal to GST_CLOCK_TIME_NONE become ClockTime::NONE
+impl From<Option<u64>> for ClockTime{
+    fn from(time: Option<u64>) -> ClockTime{
+        ClockTime::from_checked(time)
+    }
+}
+
+/// Durations that don't fit into a GstClockTime become ClockTime::NONE
+impl From<Duration> for ClockTime{
+    fn from(duration: Duration) -> ClockTime{
+        ClockTime::from_checked(duration.as_secs().checked_mul(NSECONDS_PER_SECOND)
+            .and_then(|ns| ns.checked_add(duration.subsec_nanos() as u64)))
+    }
+}
+
+/// NONE if any of the times is NONE or on overflow
+impl Add for ClockTime{
+    type Output = ClockTime;
+    fn add(self, other: ClockTime) -> ClockTime{
+        self.checked_add(other).unwrap_or(ClockTime::NONE)
+    }
+}
+
+/// NONE if any of the times is NONE or if other is bigger than self
+impl Sub for ClockTime{
+    type Output = ClockTime;
+    fn sub(self, other: ClockTime) -> ClockTime{
+        self.checked_sub(other).unwrap_or(ClockTime::NONE)
+    }
+}
+
+/// NONE if the time is NONE or on overflow
+impl Mul<u64> for ClockTime{
+    type Output = ClockTime;
+    fn mul(self, factor: u64) -> ClockTime{
+        self.checked_mul(factor).unwrap_or(ClockTime::NONE)
+    }
+}
+
+impl Mul<ClockTime> for u64{
+    type Output = ClockTime;
+    fn mul(self, time: ClockTime) -> ClockTime{
+        time * self
+    }
+}
+
+/// NONE if the time is NONE or if divisor is 0
+impl Div<u64> for ClockTime{
+    type Output = ClockTime;
+    fn div(self, divisor: u64) -> ClockTime{
+        self.checked_div(divisor).unwrap_or(ClockTime::NONE)
+    }
+}
+
+impl Display for ClockTime{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>{
+        match self.0{
+            Some(ns) => {
+                let seconds = ns / NSECONDS_PER_SECOND;
+                write!(fmt, "{}:{:02}:{:02}.{:09}",
+                    seconds / 3600,
+                    (seconds / 60) % 60,
+                    seconds % 60,
+                    ns % NSECONDS_PER_SECOND)
+            }
+            None => fmt.write_str("99:99:99.999999999")
+        }
+    }
+}
+
+/// Error returned when parsing a ClockTime from a string fails
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub struct ParseClockTimeError;
+
+impl Display for ParseClockTimeError{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>{
+        fmt.write_str("invalid clock time, expected [[h:]mm:]ss[.nnnnnnnnn]")
+    }
+}
+
+impl error::Error for ParseClockTimeError{
+    fn description(&self) -> &str{
+        "invalid clock time"
+    }
+}
+
+/// Parses times in the [[h:]mm:]ss[.nnnnnnnnn] format, as produced by
+/// Display. The fractional part can have up to 9 digits. The invalid time
+/// 99:99:99.999999999 is parsed as ClockTime::NONE.
+impl FromStr for ClockTime{
+    type Err = ParseClockTimeError;
+
+    fn from_str(string: &str) -> Result<ClockTime, ParseClockTimeError>{
+        let string = string.trim();
+        if string == "99:99:99.999999999"{
+            return Ok(ClockTime::NONE);
+        }
+
+        let (whole, fraction) = match string.find('.'){
+            Some(idx) => (&string[..idx], Some(&string[idx + 1..])),
+            None => (string, None)
+        };
+
+        let parts: Vec<&str> = whole.split(':').collect();
+        if parts.len() > 3{
+            return Err(ParseClockTimeError);
+        }
+        let mut seconds: u64 = 0;
+        for (idx, part) in parts.iter().enumerate(){
+            if part.is_empty() || !part.chars().all(|c| c.is_digit(10)){
+                return Err(ParseClockTimeError);
+            }
+            let value = part.parse::<u64>().map_err(|_| ParseClockTimeError)?;
+            // minutes and seconds after the first field must be < 60
+            if idx > 0 && value >= 60{
+                return Err(ParseClockTimeError);
+            }
+            seconds = seconds.checked_mul(60).and_then(|s| s.checked_add(value)).ok_or(ParseClockTimeError)?;
+        }
+
+        let nseconds = match fraction{
+            Some(fraction) => {
+                if fraction.is_empty() || fraction.len() > 9 || !fraction.chars().all(|c| c.is_digit(10)){
+                    return Err(ParseClockTimeError);
+                }
+                let value = fraction.parse::<u64>().map_err(|_| ParseClockTimeError)?;
+                value * 10u64.pow(9 - fraction.len() as u32)
+            }
+            None => 0
+        };
+
+        seconds.checked_mul(NSECONDS_PER_SECOND)
+            .and_then(|ns| ns.checked_add(nseconds))
+            .map(ClockTime::from_nseconds)
+            .ok_or(ParseClockTimeError)
+    }
+}
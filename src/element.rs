@@ -2,11 +2,22 @@ use ffi::*;
 use bus::Bus;
 use util::*;
 use pad::Pad;
+use pad_template::PadTemplate;
 use caps::Caps;
+use event::Event;
+use message::Message;
+use query::Query;
 use reference::Reference;
-use object::{Object, Property, FromProperty};
+use error::Error;
+use state::{self, State, StateChangeSuccess, StateChangeError, CachedState};
+use iterator::Iter;
+use seek::Seek;
+use clock_time::ClockTime;
+use object::{Object, Property, FromProperty, SignalHandlerId};
+use ::Transfer;
 
-use std::os::raw::c_void;
+use std::os::raw::{c_char, c_void};
+use std::sync::{Arc, Mutex};
 use std::ops::{Deref, DerefMut};
 
 unsafe impl Sync for GstElement {}
@@ -143,9 +154,72 @@ impl Element {
 	///
 	/// State changes to GST_STATE_READY or GST_STATE_NULL
 	/// never return GST_STATE_CHANGE_ASYNC.
-    pub fn set_state(&mut self, state: GstState) -> GstStateChangeReturn{
+	///
+	/// Returns `StateChangeSuccess::Async` for such an asynchronous state
+	/// change and `StateChangeSuccess::NoPreroll` for live sources that
+	/// can't produce data in PAUSED.
+    pub fn set_state<S: Into<State>>(&mut self, state: S) -> Result<StateChangeSuccess, StateChangeError>{
+        unsafe{
+            state::state_change_result(gst_element_set_state(self.gst_element_mut(), state.into().into()))
+        }
+    }
+
+    /// Sets the state of the element and waits up to timeout for an
+    /// asynchronous state change to complete, ClockTime::NONE waits
+    /// forever. If the state change is still in progress when the timeout
+    /// expires `StateChangeSuccess::Async` is returned.
+    ///
+    /// When the state change fails, the first error posted by the element
+    /// or one of its children while changing state is returned as part of
+    /// the `StateChangeError`. The bus isn't modified, the ERROR message
+    /// also stays on it for the watches and other messages are left
+    /// untouched.
+    ///
+    /// The error is collected with `Bus::on_sync_message`, so it's only
+    /// seen if the sync handler of the bus lets it through: when a handler
+    /// set with `Bus::set_sync_handler` returns `BusSyncReply::Drop` for
+    /// ERROR messages, the returned `StateChangeError` carries no error.
+    ///
+    /// ```ignore
+    /// if let Err(err) = pipeline.set_state_blocking(State::Paused, ClockTime::from_seconds(5)){
+    ///     println!("{}", err);
+    /// }
+    /// ```
+    pub fn set_state_blocking<S: Into<State>, T: Into<ClockTime>>(&mut self, state: S, timeout: T) -> Result<StateChangeSuccess, StateChangeError>{
+        let error: Arc<Mutex<Option<Message>>> = Arc::new(Mutex::new(None));
+        let mut bus = self.bus();
+        let handler_id = match bus{
+            Some(ref mut bus) => {
+                let element = self.reference();
+                let error = error.clone();
+                bus.on_sync_message(move |message| {
+                    if let Message::Error(_) = *message{
+                        let mut error = error.lock().unwrap();
+                        if error.is_none() && element.is_ancestor_of(message){
+                            *error = Some(message.reference());
+                        }
+                    }
+                })
+            }
+            None => None
+        };
+        let ret = match self.set_state(state){
+            Ok(StateChangeSuccess::Async) => self.get_state(timeout).2,
+            ret => ret
+        };
+        if let (Some(mut bus), Some(handler_id)) = (bus, handler_id){
+            bus.remove_sync_message_handler(handler_id);
+        }
+        let error = error.lock().unwrap().take();
+        ret.map_err(|_| state_change_error(error))
+    }
+
+    /// Returns true if the message was posted by the element or one of
+    /// its children
+    fn is_ancestor_of(&self, message: &Message) -> bool{
         unsafe{
-            gst_element_set_state(self.gst_element_mut(), state)
+            let src = message.gst_src();
+            src != ptr::null_mut() && gst_object_has_ancestor(src, self.gst_element() as *mut GstObject) != 0
         }
     }
 
@@ -155,44 +229,192 @@ impl Element {
 	/// by set_state(), this function will block up to the specified
 	/// timeout value for the state change to complete. If the element
 	/// completes the state change or goes into an error, this function
-	/// returns immediately with a return value of GST_STATE_CHANGE_SUCCESS
-	/// or GST_STATE_CHANGE_FAILURE respectively.
+	/// returns immediately with a return value of Ok(Success) or an Err
+	/// respectively.
 	///
-	/// For elements that did not return GST_STATE_CHANGE_ASYNC, this function
-	/// returns the current and pending state immediately.
+	/// For elements that did not return Async, this function returns the
+	/// current and pending state immediately.
 	///
-	/// This function returns GST_STATE_CHANGE_NO_PREROLL if the element
-	/// successfully changed its state but is not able to provide data yet.
-	/// This mostly happens for live sources that only produce data in
-	/// GST_STATE_PLAYING. While the state change return is equivalent to
-	/// GST_STATE_CHANGE_SUCCESS, it is returned to the application to signal
-	/// that some sink elements might not be able to complete their state change
-	/// because an element is not producing data to complete the preroll.
-	/// When setting the element to playing, the preroll will complete and
-	/// playback will start.
-	/// Returns
+	/// This function returns Ok(NoPreroll) if the element successfully
+	/// changed its state but is not able to provide data yet. This mostly
+	/// happens for live sources that only produce data in PLAYING. While
+	/// the state change return is equivalent to Ok(Success), it is
+	/// returned to the application to signal that some sink elements might
+	/// not be able to complete their state change because an element is
+	/// not producing data to complete the preroll. When setting the
+	/// element to playing, the preroll will complete and playback will
+	/// start.
 	///
-	/// GST_STATE_CHANGE_SUCCESS if the element has no more pending state and
-	/// the last state change succeeded, GST_STATE_CHANGE_ASYNC if the element
-	/// is still performing a state change or GST_STATE_CHANGE_FAILURE if
-	/// the last state change failed.
-    pub fn get_state(&self, timeout: GstClockTime) -> (GstState, GstState, GstStateChangeReturn){
+	/// Returns the current state, the pending state and Ok(Success) if
+	/// the element has no more pending state and the last state change
+	/// succeeded, Ok(Async) if the element is still performing a state
+	/// change or an Err if the last state change failed.
+    pub fn get_state<T: Into<ClockTime>>(&self, timeout: T) -> (State, State, Result<StateChangeSuccess, StateChangeError>){
         let mut state: GstState = GST_STATE_NULL;
         let mut pending: GstState = GST_STATE_NULL;
         unsafe{
-            let ret = gst_element_get_state(mem::transmute(self.gst_element()), &mut state, &mut pending, timeout);
-            (state, pending, ret)
+            let ret = gst_element_get_state(mem::transmute(self.gst_element()), &mut state, &mut pending, timeout.into().to_gst_clock_time());
+            (state.into(), pending.into(), state::state_change_result(ret))
         }
     }
 
+    /// Returns the states cached in the element without waiting for any
+    /// pending asynchronous state change, together with the result of the
+    /// last state change.
+    ///
+    /// When a state change is in progress `current` is the state the
+    /// element is in right now and `pending` the state it is going to,
+    /// while `get_state` would wait for the state change to complete.
+    pub fn cached_state(&self) -> CachedState{
+        unsafe{
+            let element = self.gst_element() as *mut GstElement;
+            g_mutex_lock(&mut (*element).object.lock);
+            let (current, next, pending, target, last_return) = (
+                (*element).current_state,
+                (*element).next_state,
+                (*element).pending_state,
+                (*element).target_state,
+                (*element).last_return);
+            g_mutex_unlock(&mut (*element).object.lock);
+            CachedState{
+                current: current.into(),
+                next: next.into(),
+                pending: pending.into(),
+                target: target.into(),
+                last_return: state::state_change_result(last_return),
+            }
+        }
+    }
+
+    /// Returns the state the element is currently in, without waiting
+    /// for a pending state change
+    pub fn current_state(&self) -> State{
+        self.cached_state().current
+    }
+
+    /// Returns the state the element is going to, or VoidPending if no
+    /// state change is in progress
+    pub fn pending_state(&self) -> State{
+        self.cached_state().pending
+    }
+
+    /// Tries to change the state of the element to the same as its
+    /// parent. If this function returns false, the state of element is
+    /// undefined.
+    ///
+    /// This is typically used after adding an element to an already
+    /// running bin or pipeline.
+    pub fn sync_state_with_parent(&mut self) -> bool{
+        unsafe{
+            gst_element_sync_state_with_parent(self.gst_element_mut()) != 0
+        }
+    }
+
+    /// Locks the state of an element, so state changes of the parent
+    /// don't affect this element anymore.
+    ///
+    /// Returns true if the state was changed, false if bad parameters
+    /// were given or the elements state-locking needed no change.
+    pub fn set_locked_state(&mut self, locked_state: bool) -> bool{
+        unsafe{
+            gst_element_set_locked_state(self.gst_element_mut(), locked_state as gboolean) != 0
+        }
+    }
+
+    /// Checks if the state of an element is locked. If the state of an
+    /// element is locked, state changes of the parent don't affect the
+    /// element. This way you can leave currently unused elements inside
+    /// bins. Just lock their state before changing the state from
+    /// GST_STATE_NULL.
+    pub fn is_locked_state(&self) -> bool{
+        unsafe{
+            gst_element_is_locked_state(self.gst_element() as *mut GstElement) != 0
+        }
+    }
+
+    /// Abort the state change of the element. This function is used by
+    /// elements that do asynchronous state changes and find out something
+    /// is wrong.
+    ///
+    /// # Safety
+    ///
+    /// The caller must hold the STATE_LOCK of the element, this crate
+    /// doesn't expose it so this is only usable from code that was called
+    /// by GStreamer with the lock held.
+    pub unsafe fn abort_state(&mut self){
+        gst_element_abort_state(self.gst_element_mut());
+    }
+
+    /// Brings the element to the lost state. The current state of the
+    /// element is copied to the pending state so that any call to
+    /// get_state() will return Async.
+    ///
+    /// An ASYNC_START message is posted. If the element was PLAYING, it
+    /// will go to PAUSED. The element will be restored to its PLAYING
+    /// state by the parent pipeline when it prerolls again.
+    ///
+    /// This is mostly used for elements that lost their preroll buffer in
+    /// the PAUSED or PLAYING state after a flush, they become PAUSED again
+    /// if a new preroll buffer is queued.
+    pub fn lost_state(&mut self){
+        unsafe{
+            gst_element_lost_state(self.gst_element_mut());
+        }
+    }
+
+    /// Commit the state change of the element and proceed to the next
+    /// pending state if any. This function is used by elements that do
+    /// asynchronous state changes. The core will normally call this method
+    /// automatically when an element returned Ok(Success) from the state
+    /// change function.
+    ///
+    /// If after calling this method the element still has not reached the
+    /// pending state, the next state change is performed.
+    ///
+    /// This method is used internally and should normally not be called
+    /// by plugins or applications.
+    ///
+    /// # Safety
+    ///
+    /// The caller must hold the STATE_LOCK of the element, see
+    /// `abort_state`.
+    pub unsafe fn continue_state(&mut self, ret: Result<StateChangeSuccess, StateChangeError>) -> Result<StateChangeSuccess, StateChangeError>{
+        state::state_change_result(gst_element_continue_state(self.gst_element_mut(), state::state_change_return(&ret)))
+    }
+
+    /// Perform transition on the element, like
+    /// GST_STATE_CHANGE_NULL_TO_READY. This is mainly used internally,
+    /// applications should use `set_state`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must hold the STATE_LOCK of the element, see
+    /// `abort_state`.
+    pub unsafe fn change_state(&mut self, transition: GstStateChange) -> Result<StateChangeSuccess, StateChangeError>{
+        state::state_change_result(gst_element_change_state(self.gst_element_mut(), transition))
+    }
+
     /// Sends an event to an element. If the element doesn't implement an event
     /// handler, the event will be pushed on a random linked sink pad for
     /// downstream events or a random linked source pad for upstream events.
 	///
 	/// This function takes ownership of the provided event so you should
-	/// gst_event_ref() it if you want to reuse the event after this call.
-    pub unsafe fn send_event(&mut self, event: *mut GstEvent) -> bool{
-        gst_element_send_event(self.gst_element_mut(), event) == 1
+	/// call reference() on it if you want to reuse the event after this call.
+    pub fn send_event(&mut self, event: Event) -> bool{
+        unsafe{
+            gst_element_send_event(self.gst_element_mut(), event.transfer()) == 1
+        }
+    }
+
+    /// Post a message on the element's bus. This function takes ownership
+    /// of the message.
+    ///
+    /// Returns true if the message was successfully posted. The function
+    /// returns false if the element did not have a bus.
+    pub fn post_message(&mut self, message: Message) -> bool{
+        unsafe{
+            gst_element_post_message(self.gst_element_mut(), message.transfer()) == 1
+        }
     }
 
     /// Simple API to perform a seek on the given element, meaning it just
@@ -224,6 +446,44 @@ impl Element {
         }
     }
 
+    /// Performs a query on the given element.
+    ///
+    /// For elements that don't implement a query handler, this function
+    /// forwards the query to a random srcpad or to the peer of a random
+    /// linked sinkpad of this element.
+    ///
+    /// Please note that some queries might need a running pipeline to work.
+    ///
+    /// Returns true if the query could be performed, the result can then be
+    /// read with the corresponding parse method of the query.
+    pub fn query(&self, query: &mut Query) -> bool{
+        unsafe{
+            gst_element_query(self.gst_element() as *mut GstElement, query.gst_query_mut()) != 0
+        }
+    }
+
+    /// Performs the seek described by seek on the element, usually a
+    /// pipeline or playbin.
+    ///
+    /// Returns an error if the seek parameters are invalid or if the seek
+    /// was rejected, for example because the stream is not seekable or
+    /// the requested format is not supported.
+    ///
+    /// ```ignore
+    /// pipeline.seek_with(&Seek::new().flush().key_unit().snap_before().start(position))?;
+    /// ```
+    pub fn seek_with(&mut self, seek: &Seek) -> Result<(), Error>{
+        let event = match seek.to_event(){
+            Some(event) => event,
+            None => return Err(Error::new(unsafe{ gst_core_error_quark() }, GST_CORE_ERROR_SEEK as i32, "Invalid seek parameters"))
+        };
+        if self.send_event(event){
+            Ok(())
+        }else{
+            Err(Error::new(unsafe{ gst_core_error_quark() }, GST_CORE_ERROR_SEEK as i32, "Seek was rejected"))
+        }
+    }
+
     /// Queries an element (usually top-level pipeline or playbin element)
     /// for the total stream duration in nanoseconds. This query will only
     /// work once the pipeline is prerolled (i.e. reached PAUSED or PLAYING
@@ -261,6 +521,20 @@ impl Element {
         }
     }
 
+    /// The duration of the stream, NONE if it couldn't be queried
+    pub fn duration(&self) -> ClockTime{
+        self.query_duration(GST_FORMAT_TIME)
+            .map(|duration| ClockTime::from_gst_clock_time(duration as u64))
+            .unwrap_or(ClockTime::NONE)
+    }
+
+    /// The current position in the stream, NONE if it couldn't be queried
+    pub fn position(&self) -> ClockTime{
+        self.query_position(GST_FORMAT_TIME)
+            .map(|position| ClockTime::from_gst_clock_time(position as u64))
+            .unwrap_or(ClockTime::NONE)
+    }
+
     /// Shortcut for query_duration with format == TIME
     pub fn duration_ns(&self) -> Option<i64>{
         self.query_duration(GST_FORMAT_TIME)
@@ -305,9 +579,7 @@ impl Element {
 
     /// Shortcut for seek to a ceratin position in ns
     pub fn set_position_ns(&mut self, ns: i64) -> bool{
-        let format = GST_FORMAT_TIME;
-	    let flags = GST_SEEK_FLAG_FLUSH; // | GST_SEEK_FLAG_ACCURATE |
-		self.seek_simple(format, flags,	ns)
+        self.seek_with(&Seek::new().flush().start(ns)).is_ok()
     }
 
     /// Shortcut for seek to a ceratin position in secs
@@ -327,10 +599,8 @@ impl Element {
     /// Shortcut for seek to the current position but change in playback
     /// rate
     pub fn set_speed(&mut self, speed: f64) -> bool{
-        let format = GST_FORMAT_TIME;
-	    let flags = GST_SEEK_FLAG_SKIP | GST_SEEK_FLAG_ACCURATE | GST_SEEK_FLAG_FLUSH;
         if speed==0.0 {
-            return self.set_state(GST_STATE_PAUSED) != GST_STATE_CHANGE_FAILURE;
+            return self.pause().is_ok();
         }
 
         let pos_opt = self.query_position(GST_FORMAT_TIME);
@@ -340,77 +610,127 @@ impl Element {
 
         let pos = pos_opt.unwrap();
 
-        if speed > 0.0 {
-            self.seek(speed, format,
-                    flags,
-                    GST_SEEK_TYPE_SET,
-                    pos,
-                    GST_SEEK_TYPE_SET,
-                    -1)
+        let seek = Seek::new().rate(speed).flush().accurate().trickmode();
+        let seek = if speed > 0.0 {
+            seek.start(pos).stop(-1)
         } else {
-            self.seek(speed, format,
-                    flags,
-                    GST_SEEK_TYPE_SET,
-                    0,
-                    GST_SEEK_TYPE_SET,
-                    pos)
-        }
+            seek.start(0).stop(pos)
+        };
+        self.seek_with(&seek).is_ok()
     }
 
     /// shortcut to set_state with state == NULL
-    pub fn set_null_state(&mut self) -> GstStateChangeReturn{
-        self.set_state(GST_STATE_NULL)
+    pub fn set_null_state(&mut self) -> Result<StateChangeSuccess, StateChangeError>{
+        self.set_state(State::Null)
     }
 
     /// shortcut to set_state with state == READY
-    pub fn set_ready_state(&mut self) -> GstStateChangeReturn{
-        self.set_state(GST_STATE_READY)
+    pub fn set_ready_state(&mut self) -> Result<StateChangeSuccess, StateChangeError>{
+        self.set_state(State::Ready)
     }
 
     /// shortcut to set_state with state == PAUSED
-    pub fn pause(&mut self) -> GstStateChangeReturn{
-        self.set_state(GST_STATE_PAUSED)
+    pub fn pause(&mut self) -> Result<StateChangeSuccess, StateChangeError>{
+        self.set_state(State::Paused)
     }
 
     /// shortcut to set_state with state == PLAYING
-    pub fn play(&mut self) -> GstStateChangeReturn{
-        self.set_state(GST_STATE_PLAYING)
+    pub fn play(&mut self) -> Result<StateChangeSuccess, StateChangeError>{
+        self.set_state(State::Playing)
     }
 
     /// shortcut to query the state and returns state == PAUSED
     pub fn is_paused(&self) -> bool{
-        if let (GST_STATE_PAUSED, _pending, GST_STATE_CHANGE_SUCCESS) = self.get_state(GST_CLOCK_TIME_NONE){
-			true
-		}else{
-			false
-		}
+        self.is_in_state(State::Paused)
     }
 
     /// shortcut to query the state and returns state == PLAYING
     pub fn is_playing(&self) -> bool{
-        if let (GST_STATE_PLAYING, _pending, GST_STATE_CHANGE_SUCCESS) = self.get_state(GST_CLOCK_TIME_NONE){
-			true
-		}else{
-			false
-		}
+        self.is_in_state(State::Playing)
     }
 
     /// shortcut to query the state and returns state == NULL
     pub fn is_null_state(&self) -> bool{
-        if let (GST_STATE_NULL, _pending, GST_STATE_CHANGE_SUCCESS) = self.get_state(GST_CLOCK_TIME_NONE){
-			true
-		}else{
-			false
-		}
+        self.is_in_state(State::Null)
     }
 
     /// shortcut to query the state and returns state == READY
     pub fn is_ready_state(&self) -> bool{
-        if let (GST_STATE_READY, _pending, GST_STATE_CHANGE_SUCCESS) = self.get_state(GST_CLOCK_TIME_NONE){
-			true
-		}else{
-			false
-		}
+        self.is_in_state(State::Ready)
+    }
+
+    fn is_in_state(&self, state: State) -> bool{
+        match self.get_state(ClockTime::NONE){
+            (current, _pending, Ok(StateChangeSuccess::Success)) => current == state,
+            _ => false
+        }
+    }
+
+    /// Calls callback every time a new pad is added to the element, for
+    /// example when a demuxer or decodebin found a new stream. The callback
+    /// receives the element and the new pad and is called from a streaming
+    /// thread.
+    pub fn on_pad_added<F>(&mut self, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&Element, &Pad) + Send + Sync + 'static{
+        self.element.connect("pad-added", move |args| {
+            if let (Some(element), Some(pad)) = (args.get(0).and_then(|arg| arg.get::<Element>()), args.get(1).and_then(|arg| arg.get::<Pad>())){
+                callback(&element, &pad);
+            }
+            None
+        })
+    }
+
+    /// Calls callback every time a pad is removed from the element.
+    pub fn on_pad_removed<F>(&mut self, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&Element, &Pad) + Send + Sync + 'static{
+        self.element.connect("pad-removed", move |args| {
+            if let (Some(element), Some(pad)) = (args.get(0).and_then(|arg| arg.get::<Element>()), args.get(1).and_then(|arg| arg.get::<Pad>())){
+                callback(&element, &pad);
+            }
+            None
+        })
+    }
+
+    /// Calls callback when the element will not generate more dynamic
+    /// pads.
+    pub fn on_no_more_pads<F>(&mut self, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&Element) + Send + Sync + 'static{
+        self.element.connect("no-more-pads", move |args| {
+            if let Some(element) = args.get(0).and_then(|arg| arg.get::<Element>()){
+                callback(&element);
+            }
+            None
+        })
+    }
+
+    /// Links every new pad of the element whose caps have a media type
+    /// starting with media_type_prefix, for example "video/" or
+    /// "audio/x-raw", to the "sink" pad of target, as long as that one is
+    /// not linked yet. This is the usual way of connecting the dynamic
+    /// pads of decodebin, uridecodebin or demuxers.
+    ///
+    /// ```ignore
+    /// decodebin.auto_link(&videosink, "video/");
+    /// decodebin.auto_link(&audiosink, "audio/");
+    /// ```
+    pub fn auto_link(&mut self, target: &Element, media_type_prefix: &str) -> Option<SignalHandlerId>{
+        let target = target.reference();
+        let media_type_prefix = media_type_prefix.to_string();
+        self.on_pad_added(move |_element, pad| {
+            let mut sink_pad = match target.reference().static_pad("sink"){
+                Some(sink_pad) => sink_pad,
+                None => return
+            };
+            if sink_pad.is_linked(){
+                return;
+            }
+            let matches = pad.query_caps(None)
+                .map(|caps| caps.structures().any(|structure| structure.name().starts_with(&media_type_prefix)))
+                .unwrap_or(false);
+            if matches{
+                let _ = pad.reference().link(&mut sink_pad);
+            }
+        })
     }
 
     // Retrieves a pad from element by name.
@@ -423,6 +743,154 @@ impl Element {
         }
     }
 
+    /// Gets an iterator over all the pads of the element.
+    pub fn iter_pads(&self) -> Iter<Pad>{
+        unsafe{
+            Iter::new_from_gst_iterator(gst_element_iterate_pads(self.gst_element() as *mut GstElement)).unwrap()
+        }
+    }
+
+    /// Gets an iterator over the source pads of the element.
+    pub fn iter_src_pads(&self) -> Iter<Pad>{
+        unsafe{
+            Iter::new_from_gst_iterator(gst_element_iterate_src_pads(self.gst_element() as *mut GstElement)).unwrap()
+        }
+    }
+
+    /// Gets an iterator over the sink pads of the element.
+    pub fn iter_sink_pads(&self) -> Iter<Pad>{
+        unsafe{
+            Iter::new_from_gst_iterator(gst_element_iterate_sink_pads(self.gst_element() as *mut GstElement)).unwrap()
+        }
+    }
+
+    /// Retrieves a pad template from the element's class with the given
+    /// name, like "sink" or "src_%u".
+    pub fn pad_template(&self, name: &str) -> Option<PadTemplate>{
+        let cname = CString::new(name).unwrap();
+        unsafe{
+            let templ = gst_element_class_get_pad_template(self.gst_element_class(), cname.as_ptr());
+            if templ != ptr::null_mut(){
+                gst_object_ref(templ as gpointer);
+            }
+            PadTemplate::new_from_gst_pad_template(templ)
+        }
+    }
+
+    /// Retrieves all the pad templates of the element's class.
+    pub fn pad_templates(&self) -> Vec<PadTemplate>{
+        let mut templates = Vec::new();
+        unsafe{
+            let mut list = gst_element_class_get_pad_template_list(self.gst_element_class());
+            while list != ptr::null_mut(){
+                let templ = (*list).data as *mut GstPadTemplate;
+                if templ != ptr::null_mut(){
+                    gst_object_ref(templ as gpointer);
+                }
+                if let Some(templ) = PadTemplate::new_from_gst_pad_template(templ){
+                    templates.push(templ);
+                }
+                list = (*list).next;
+            }
+        }
+        templates
+    }
+
+    /// Retrieves a request pad from the element according to the provided
+    /// template name, like "src_%u" for tee or "sink_%u" for mixers.
+    ///
+    /// If name is None a unique name is chosen by the element. The
+    /// optional caps are a hint for the element about the media type of
+    /// the pad. Returns None if there's no template with that name or if
+    /// it's not a request template.
+    ///
+    /// The returned RequestPad releases the pad from the element again
+    /// when dropped, use `RequestPad::into_pad` to keep it around for the
+    /// lifetime of the element instead.
+    ///
+    /// ```ignore
+    /// let mut tee_src = tee.request_pad("src_%u", None, None).unwrap();
+    /// let mut queue_sink = queue.static_pad("sink").unwrap();
+    /// tee_src.link(&mut queue_sink).unwrap();
+    /// ```
+    pub fn request_pad(&mut self, template_name: &str, name: Option<&str>, caps: Option<&Caps>) -> Option<RequestPad>{
+        let mut templ = match self.pad_template(template_name){
+            Some(ref templ) if templ.presence() != GST_PAD_REQUEST => return None,
+            Some(templ) => templ,
+            None => return None
+        };
+        let cname = name.map(|name| CString::new(name).unwrap());
+        unsafe{
+            let pad = gst_element_request_pad(self.gst_element_mut(),
+                templ.gst_pad_template_mut(),
+                cname.as_ref().map(|cname| cname.as_ptr()).unwrap_or(ptr::null()),
+                caps.map(|caps| caps.gst_caps()).unwrap_or(ptr::null()));
+            Pad::new(pad).map(|pad| RequestPad{
+                element: self.reference(),
+                pad: Some(pad),
+            })
+        }
+    }
+
+    /// Makes the element free the previously requested pad as obtained
+    /// with `request_pad`.
+    ///
+    /// This does not unref the pad. If the pad was created by using
+    /// `request_pad`, dropping the RequestPad does this automatically.
+    pub fn release_request_pad(&mut self, pad: &Pad){
+        unsafe{
+            gst_element_release_request_pad(self.gst_element_mut(), pad.gst_pad() as *mut GstPad);
+        }
+    }
+
+    /// Looks for an unlinked pad to which the given pad can link. It is
+    /// not guaranteed that linking the pads will work, though it should
+    /// work in most cases.
+    ///
+    /// This function will first attempt to find a compatible unlinked
+    /// ALWAYS pad, and if none can be found, it will request a compatible
+    /// REQUEST pad by looking at the templates of the element.
+    ///
+    /// The optional caps are used as a filter.
+    pub fn compatible_pad(&mut self, pad: &Pad, caps: Option<&Caps>) -> Option<Pad>{
+        unsafe{
+            let pad = gst_element_get_compatible_pad(self.gst_element_mut(),
+                pad.gst_pad() as *mut GstPad,
+                caps.map(|caps| caps.gst_caps() as *mut GstCaps).unwrap_or(ptr::null_mut()));
+            Pad::new(pad)
+        }
+    }
+
+    /// Adds a pad (link point) to the element. The pad's parent will be
+    /// set to the element and the element takes its own reference to the
+    /// pad.
+    ///
+    /// Pads are automatically activated when added in the PAUSED or
+    /// PLAYING state.
+    ///
+    /// Returns false if the pad could not be added. This function can fail
+    /// when a pad with the same name already existed or the pad already
+    /// had another parent.
+    pub fn add_pad(&mut self, pad: &Pad) -> bool{
+        unsafe{
+            gst_element_add_pad(self.gst_element_mut(), pad.gst_pad() as *mut GstPad) != 0
+        }
+    }
+
+    /// Removes pad from the element. The pad will be deactivated and
+    /// unlinked if it was linked.
+    ///
+    /// Returns false if the pad does not belong to the element.
+    pub fn remove_pad(&mut self, pad: &Pad) -> bool{
+        unsafe{
+            gst_element_remove_pad(self.gst_element_mut(), pad.gst_pad() as *mut GstPad) != 0
+        }
+    }
+
+    unsafe fn gst_element_class(&self) -> *mut GstElementClass{
+        (*(self.gst_element() as *mut GTypeInstance)).g_class as *mut GstElementClass
+    }
+
     /// Returns a const raw pointer to the internal GstElement
     pub unsafe fn gst_element(&self) -> *const GstElement{
         self.element.gst_object() as *const GstElement
@@ -521,3 +989,83 @@ impl<'a> FromProperty for ::Ref<Element>{
         }
     }
 }
+
+/// A pad requested from an element with `Element::request_pad`. The pad is
+/// released from the element when the RequestPad is dropped.
+pub struct RequestPad{
+    element: Element,
+    pad: Option<Pad>,
+}
+
+impl RequestPad{
+    /// The element the pad was requested from
+    pub fn element(&self) -> &Element{
+        &self.element
+    }
+
+    /// Releases the pad from the element right away.
+    pub fn release(self){
+        // consuming self is enough, Drop releases the pad
+    }
+
+    /// Returns the pad without releasing it, the pad then stays on the
+    /// element until it is released with `Element::release_request_pad`.
+    pub fn into_pad(mut self) -> Pad{
+        self.pad.take().unwrap()
+    }
+}
+
+impl Drop for RequestPad{
+    fn drop(&mut self){
+        if let Some(pad) = self.pad.take(){
+            self.element.release_request_pad(&pad);
+        }
+    }
+}
+
+impl AsRef<Pad> for RequestPad{
+    fn as_ref(&self) -> &Pad{
+        self.pad.as_ref().unwrap()
+    }
+}
+
+impl AsMut<Pad> for RequestPad{
+    fn as_mut(&mut self) -> &mut Pad{
+        self.pad.as_mut().unwrap()
+    }
+}
+
+impl Deref for RequestPad{
+    type Target = Pad;
+    fn deref(&self) -> &Pad{
+        self.pad.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for RequestPad{
+    fn deref_mut(&mut self) -> &mut Pad{
+        self.pad.as_mut().unwrap()
+    }
+}
+
+/// Builds a StateChangeError from the ERROR message of a failed state
+/// change, if any
+fn state_change_error(message: Option<Message>) -> StateChangeError{
+    let message = match message{
+        Some(message) => message,
+        None => return StateChangeError::new()
+    };
+    unsafe{
+        let mut error: *mut GError = ptr::null_mut();
+        let mut debug: *mut c_char = ptr::null_mut();
+        gst_message_parse_error(message.gst_message() as *mut GstMessage, &mut error, &mut debug);
+        let debug = if debug != ptr::null_mut(){
+            let string = from_c_str!(debug).to_string();
+            g_free(debug as gpointer);
+            Some(string)
+        }else{
+            None
+        };
+        StateChangeError::new_with_error(Error::new_from_g_error(error), debug, message.src_path())
+    }
+}
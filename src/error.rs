@@ -68,6 +68,12 @@ impl Error{
             }
         }
     }
+
+    /// Returns a const raw pointer to the underlying GError, NULL for
+    /// errors without one. The pointer is only valid while self is alive.
+    pub unsafe fn gst_error(&self) -> *const GError{
+        self.error
+    }
 }
 
 impl Display for Error{
@@ -0,0 +1,612 @@
+use ffi::*;
+use util::*;
+use std::os::raw::c_char;
+use std::ops::{Deref, DerefMut};
+
+use caps::Caps;
+use message::Message;
+use segment::Segment;
+use structure::Structure;
+use tag_list::TagList;
+use reference::Reference;
+use miniobject::MiniObject;
+use ::Transfer;
+
+unsafe impl Send for Event {}
+
+/// The event class provides factory methods to construct events for
+/// sending and functions to query (parse) received events.
+///
+/// Events are usually created with the `new_*` constructors, which
+/// return an event of the requested type. Events can then be sent
+/// upstream or downstream with `Element::send_event`, `Pad::send_event`
+/// or `Pad::push_event`.
+#[derive(Clone)]
+pub struct Event{
+    event: MiniObject
+}
+
+/// Parsed contents of a seek event
+#[derive(Debug,Clone,Copy)]
+pub struct SeekInfo{
+    pub rate: f64,
+    pub format: GstFormat,
+    pub flags: GstSeekFlags,
+    pub start_type: GstSeekType,
+    pub start: i64,
+    pub stop_type: GstSeekType,
+    pub stop: i64,
+}
+
+/// Parsed contents of a step event
+#[derive(Debug,Clone,Copy)]
+pub struct StepInfo{
+    pub format: GstFormat,
+    pub amount: u64,
+    pub rate: f64,
+    pub flush: bool,
+    pub intermediate: bool,
+}
+
+/// Parsed contents of a QoS event
+#[derive(Debug,Clone,Copy)]
+pub struct QosInfo{
+    pub ty: GstQOSType,
+    pub proportion: f64,
+    pub diff: GstClockTimeDiff,
+    pub timestamp: GstClockTime,
+}
+
+impl Event{
+    pub unsafe fn new(event: *mut GstEvent) -> Option<Event>{
+        MiniObject::new_from_gst_miniobject(event as *mut GstMiniObject)
+            .map(|miniobject| Event{ event: miniobject })
+    }
+
+    /// Creates a new EOS event. The eos event can only travel downstream
+    /// synchronized with the buffer flow. Elements that receive the EOS
+    /// event on a pad can return GST_FLOW_EOS as a GstFlowReturn when data
+    /// after the EOS event arrives.
+    pub fn new_eos() -> Event{
+        unsafe{
+            Event::new(gst_event_new_eos()).unwrap()
+        }
+    }
+
+    /// Allocate a new flush start event. The flush start event can be sent
+    /// upstream and downstream and travels out-of-bounds with the dataflow.
+    ///
+    /// It marks pads as being flushing and will make them return
+    /// GST_FLOW_FLUSHING when used for data flow with push or pull.
+    pub fn new_flush_start() -> Event{
+        unsafe{
+            Event::new(gst_event_new_flush_start()).unwrap()
+        }
+    }
+
+    /// Allocate a new flush stop event. The flush stop event can be sent
+    /// upstream and downstream and travels serialized with the dataflow.
+    /// It is typically sent after sending a FLUSH_START event to make the
+    /// pads accept data again.
+    ///
+    /// If reset_time is true the running time of the pipeline is reset to 0.
+    pub fn new_flush_stop(reset_time: bool) -> Event{
+        unsafe{
+            Event::new(gst_event_new_flush_stop(reset_time as gboolean)).unwrap()
+        }
+    }
+
+    /// Allocate a new seek event with the given parameters.
+    ///
+    /// The seek event configures playback of the pipeline between start
+    /// to stop at the speed given in rate, also called a playback segment.
+    /// See [gst_event_new_seek()](http://gstreamer.freedesktop.org/data/doc/gstreamer/head/gstreamer/html/GstEvent.html#gst-event-new-seek)
+    /// for the details of the parameters.
+    ///
+    /// Returns None if the parameters are not valid, for example a rate of 0.
+    pub fn new_seek(rate: f64, format: GstFormat, flags: GstSeekFlags, start_type: GstSeekType, start: i64, stop_type: GstSeekType, stop: i64) -> Option<Event>{
+        unsafe{
+            Event::new(gst_event_new_seek(rate, format, flags, start_type, start, stop_type, stop))
+        }
+    }
+
+    /// Create a new step event. The purpose of the step event is to
+    /// instruct a sink to skip amount (expressed in format) of media.
+    /// It can be used to implement stepping through the video frame by
+    /// frame or for doing fast trick modes.
+    ///
+    /// Returns None if the parameters are not valid, for example a rate
+    /// that is not positive.
+    pub fn new_step(format: GstFormat, amount: u64, rate: f64, flush: bool, intermediate: bool) -> Option<Event>{
+        unsafe{
+            Event::new(gst_event_new_step(format, amount, rate, flush as gboolean, intermediate as gboolean))
+        }
+    }
+
+    /// Create a new SEGMENT event for segment. The segment event can only
+    /// travel downstream synchronized with the buffer flow and contains
+    /// timing information and playback properties for the buffers that
+    /// will follow.
+    pub fn new_segment(segment: &Segment) -> Option<Event>{
+        unsafe{
+            Event::new(gst_event_new_segment(segment.gst_segment()))
+        }
+    }
+
+    /// Create a new CAPS event for caps. The caps event can only travel
+    /// downstream synchronized with the buffer flow and contains the
+    /// format of the buffers that will follow after the event.
+    pub fn new_caps(caps: &Caps) -> Option<Event>{
+        unsafe{
+            Event::new(gst_event_new_caps(caps.gst_caps() as *mut GstCaps))
+        }
+    }
+
+    /// Generates a metadata tag event from the given taglist.
+    ///
+    /// The scope of the taglist specifies if the taglist applies to the
+    /// complete medium or only to this specific stream.
+    pub fn new_tag(taglist: TagList) -> Option<Event>{
+        unsafe{
+            Event::new(gst_event_new_tag(taglist.transfer()))
+        }
+    }
+
+    /// Allocate a new qos event with the given values.
+    ///
+    /// The QOS event is generated in an element that wants an upstream
+    /// element to either reduce or increase its rate because of high/low
+    /// CPU load or other resource usage such as network performance or
+    /// throttling.
+    pub fn new_qos(ty: GstQOSType, proportion: f64, diff: GstClockTimeDiff, timestamp: GstClockTime) -> Option<Event>{
+        unsafe{
+            Event::new(gst_event_new_qos(ty, proportion, diff, timestamp))
+        }
+    }
+
+    /// Create a new latency event. The event is sent upstream from the
+    /// sinks and notifies elements that they should add an additional
+    /// latency to the running time before synchronising against the clock.
+    pub fn new_latency(latency: GstClockTime) -> Event{
+        unsafe{
+            Event::new(gst_event_new_latency(latency)).unwrap()
+        }
+    }
+
+    /// Create a new reconfigure event. The purpose of the reconfigure event
+    /// is to travel upstream and make elements renegotiate their caps or
+    /// reconfigure their buffer pools.
+    pub fn new_reconfigure() -> Event{
+        unsafe{
+            Event::new(gst_event_new_reconfigure()).unwrap()
+        }
+    }
+
+    /// Create a new STREAM_START event. The stream start event can only
+    /// travel downstream synchronized with the buffer flow. It is expected
+    /// to be the first event that is sent for a new stream.
+    pub fn new_stream_start(stream_id: &str) -> Event{
+        let cstream_id = CString::new(stream_id).unwrap();
+        unsafe{
+            Event::new(gst_event_new_stream_start(cstream_id.as_ptr())).unwrap()
+        }
+    }
+
+    /// Create a new GAP event. A gap event can be thought of as conceptually
+    /// equivalent to a buffer to signal that there is no data for a certain
+    /// amount of time.
+    pub fn new_gap(timestamp: GstClockTime, duration: GstClockTime) -> Option<Event>{
+        unsafe{
+            Event::new(gst_event_new_gap(timestamp, duration))
+        }
+    }
+
+    /// Create a new sink-message event. The purpose of the sink-message
+    /// event is to instruct a sink to post the message contained in the
+    /// event synchronized with the stream.
+    pub fn new_sink_message(name: &str, msg: &Message) -> Event{
+        let cname = CString::new(name).unwrap();
+        unsafe{
+            Event::new(gst_event_new_sink_message(cname.as_ptr(), msg.gst_message() as *mut GstMessage)).unwrap()
+        }
+    }
+
+    /// Create a new custom-typed event. This can be used for anything not
+    /// handled by other event-specific functions to pass an event to another
+    /// element.
+    ///
+    /// The type has to be one of the GST_EVENT_CUSTOM_* types.
+    pub fn new_custom(ty: GstEventType, structure: Structure) -> Option<Event>{
+        unsafe{
+            Event::new(gst_event_new_custom(ty, structure.transfer()))
+        }
+    }
+
+    /// Shortcut for new_custom with type GST_EVENT_CUSTOM_UPSTREAM
+    pub fn new_custom_upstream(structure: Structure) -> Option<Event>{
+        Event::new_custom(GST_EVENT_CUSTOM_UPSTREAM, structure)
+    }
+
+    /// Shortcut for new_custom with type GST_EVENT_CUSTOM_DOWNSTREAM
+    pub fn new_custom_downstream(structure: Structure) -> Option<Event>{
+        Event::new_custom(GST_EVENT_CUSTOM_DOWNSTREAM, structure)
+    }
+
+    /// Shortcut for new_custom with type GST_EVENT_CUSTOM_DOWNSTREAM_OOB
+    pub fn new_custom_downstream_oob(structure: Structure) -> Option<Event>{
+        Event::new_custom(GST_EVENT_CUSTOM_DOWNSTREAM_OOB, structure)
+    }
+
+    /// Shortcut for new_custom with type GST_EVENT_CUSTOM_BOTH
+    pub fn new_custom_both(structure: Structure) -> Option<Event>{
+        Event::new_custom(GST_EVENT_CUSTOM_BOTH, structure)
+    }
+
+    pub fn ty(&self) -> GstEventType{
+        unsafe{
+            (*self.gst_event())._type
+        }
+    }
+
+    pub fn type_name(&self) -> &str{
+        unsafe{
+            from_c_str!(gst_event_type_get_name(self.ty()))
+        }
+    }
+
+    pub fn timestamp(&self) -> GstClockTime{
+        unsafe{
+            (*self.gst_event()).timestamp
+        }
+    }
+
+    pub fn seqnum(&self) -> u32{
+        unsafe{
+            gst_event_get_seqnum(self.gst_event() as *mut GstEvent)
+        }
+    }
+
+    /// Sets the sequence number of the event. The event is made writable
+    /// first if needed.
+    pub fn set_seqnum(&mut self, seqnum: u32){
+        unsafe{
+            self.make_writable_in_place();
+            gst_event_set_seqnum(self.gst_event_mut(), seqnum)
+        }
+    }
+
+    pub fn running_time_offset(&self) -> i64{
+        unsafe{
+            gst_event_get_running_time_offset(self.gst_event() as *mut GstEvent)
+        }
+    }
+
+    /// Sets the running time offset of the event. The event is made
+    /// writable first if needed.
+    pub fn set_running_time_offset(&mut self, offset: i64){
+        unsafe{
+            self.make_writable_in_place();
+            gst_event_set_running_time_offset(self.gst_event_mut(), offset)
+        }
+    }
+
+    fn type_flags(&self) -> GstEventTypeFlags{
+        unsafe{
+            gst_event_type_get_flags(self.ty())
+        }
+    }
+
+    /// Returns true if the event can travel upstream
+    pub fn is_upstream(&self) -> bool{
+        self.type_flags() & GST_EVENT_TYPE_UPSTREAM != 0
+    }
+
+    /// Returns true if the event can travel downstream
+    pub fn is_downstream(&self) -> bool{
+        self.type_flags() & GST_EVENT_TYPE_DOWNSTREAM != 0
+    }
+
+    /// Returns true if the event is serialized with the data flow
+    pub fn is_serialized(&self) -> bool{
+        self.type_flags() & GST_EVENT_TYPE_SERIALIZED != 0
+    }
+
+    /// Returns true if the event is sticky on the pads
+    pub fn is_sticky(&self) -> bool{
+        self.type_flags() & GST_EVENT_TYPE_STICKY != 0
+    }
+
+    pub fn is_writable(&self) -> bool{
+        unsafe{
+            gst_mini_object_is_writable(self.event.gst_miniobject()) != 0
+        }
+    }
+
+    /// Checks if event has the given name. This function is usually used
+    /// to check the name of a custom event.
+    pub fn has_name(&self, name: &str) -> bool{
+        let cname = CString::new(name).unwrap();
+        unsafe{
+            gst_event_has_name(self.gst_event() as *mut GstEvent, cname.as_ptr()) != 0
+        }
+    }
+
+    /// Access the structure of the event.
+    pub fn structure(&self) -> Option<Structure>{
+        unsafe{
+            let structure = gst_event_get_structure(self.gst_event() as *mut GstEvent);
+            if structure != ptr::null(){
+                Structure::new_from_gst_structure(gst_structure_copy(structure))
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Get the reset_time field of a FLUSH_STOP event
+    pub fn parse_flush_stop(&self) -> Option<bool>{
+        if self.ty() != GST_EVENT_FLUSH_STOP{
+            return None;
+        }
+        unsafe{
+            let mut reset_time = 0;
+            gst_event_parse_flush_stop(self.gst_event() as *mut GstEvent, &mut reset_time);
+            Some(reset_time != 0)
+        }
+    }
+
+    /// Parses the parameters of a SEEK event
+    pub fn parse_seek(&self) -> Option<SeekInfo>{
+        if self.ty() != GST_EVENT_SEEK{
+            return None;
+        }
+        unsafe{
+            let mut seek = SeekInfo{
+                rate: 0.0,
+                format: GST_FORMAT_UNDEFINED,
+                flags: GST_SEEK_FLAG_NONE,
+                start_type: GST_SEEK_TYPE_NONE,
+                start: 0,
+                stop_type: GST_SEEK_TYPE_NONE,
+                stop: 0,
+            };
+            gst_event_parse_seek(self.gst_event() as *mut GstEvent,
+                                 &mut seek.rate, &mut seek.format, &mut seek.flags,
+                                 &mut seek.start_type, &mut seek.start,
+                                 &mut seek.stop_type, &mut seek.stop);
+            Some(seek)
+        }
+    }
+
+    /// Parses the parameters of a STEP event
+    pub fn parse_step(&self) -> Option<StepInfo>{
+        if self.ty() != GST_EVENT_STEP{
+            return None;
+        }
+        unsafe{
+            let mut format = GST_FORMAT_UNDEFINED;
+            let mut amount = 0;
+            let mut rate = 0.0;
+            let mut flush = 0;
+            let mut intermediate = 0;
+            gst_event_parse_step(self.gst_event() as *mut GstEvent, &mut format, &mut amount, &mut rate, &mut flush, &mut intermediate);
+            Some(StepInfo{
+                format: format,
+                amount: amount,
+                rate: rate,
+                flush: flush != 0,
+                intermediate: intermediate != 0,
+            })
+        }
+    }
+
+    /// Returns a copy of the segment of a SEGMENT event
+    pub fn parse_segment(&self) -> Option<Segment>{
+        if self.ty() != GST_EVENT_SEGMENT{
+            return None;
+        }
+        unsafe{
+            let mut segment: *const GstSegment = ptr::null();
+            gst_event_parse_segment(self.gst_event() as *mut GstEvent, &mut segment);
+            if segment != ptr::null(){
+                Some(Segment::new_from_gst_segment(&*segment))
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Get the caps of a CAPS event
+    pub fn parse_caps(&self) -> Option<Caps>{
+        if self.ty() != GST_EVENT_CAPS{
+            return None;
+        }
+        unsafe{
+            let mut caps: *mut GstCaps = ptr::null_mut();
+            gst_event_parse_caps(self.gst_event() as *mut GstEvent, &mut caps);
+            if caps != ptr::null_mut(){
+                Caps::new(gst_mini_object_ref(caps as *mut GstMiniObject) as *mut GstCaps)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Parses a TAG event and returns the taglist it contains.
+    pub fn parse_tag(&self) -> Option<TagList>{
+        if self.ty() != GST_EVENT_TAG{
+            return None;
+        }
+        unsafe{
+            let mut taglist: *mut GstTagList = ptr::null_mut();
+            gst_event_parse_tag(self.gst_event() as *mut GstEvent, &mut taglist);
+            if taglist != ptr::null_mut(){
+                TagList::new(gst_mini_object_ref(taglist as *mut GstMiniObject) as *mut GstTagList)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Parses the parameters of a QOS event
+    pub fn parse_qos(&self) -> Option<QosInfo>{
+        if self.ty() != GST_EVENT_QOS{
+            return None;
+        }
+        unsafe{
+            let mut qos = QosInfo{
+                ty: GST_QOS_TYPE_OVERFLOW,
+                proportion: 0.0,
+                diff: 0,
+                timestamp: 0,
+            };
+            gst_event_parse_qos(self.gst_event() as *mut GstEvent, &mut qos.ty, &mut qos.proportion, &mut qos.diff, &mut qos.timestamp);
+            Some(qos)
+        }
+    }
+
+    /// Get the latency of a LATENCY event
+    pub fn parse_latency(&self) -> Option<GstClockTime>{
+        if self.ty() != GST_EVENT_LATENCY{
+            return None;
+        }
+        unsafe{
+            let mut latency = 0;
+            gst_event_parse_latency(self.gst_event() as *mut GstEvent, &mut latency);
+            Some(latency)
+        }
+    }
+
+    /// Get the stream id of a STREAM_START event
+    pub fn parse_stream_start(&self) -> Option<String>{
+        if self.ty() != GST_EVENT_STREAM_START{
+            return None;
+        }
+        unsafe{
+            let mut stream_id: *const c_char = ptr::null();
+            gst_event_parse_stream_start(self.gst_event() as *mut GstEvent, &mut stream_id);
+            if stream_id != ptr::null(){
+                Some(from_c_str!(stream_id).to_string())
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Set the group id of a STREAM_START event. All streams that have
+    /// the same group id are supposed to be played together. The event is
+    /// made writable first if needed.
+    ///
+    /// Returns false, without changing the event, if it's not a
+    /// STREAM_START event.
+    pub fn set_group_id(&mut self, group_id: u32) -> bool{
+        if self.ty() != GST_EVENT_STREAM_START{
+            return false;
+        }
+        unsafe{
+            self.make_writable_in_place();
+            gst_event_set_group_id(self.gst_event_mut(), group_id);
+        }
+        true
+    }
+
+    /// Get the group id of a STREAM_START event if it has one
+    pub fn group_id(&self) -> Option<u32>{
+        if self.ty() != GST_EVENT_STREAM_START{
+            return None;
+        }
+        unsafe{
+            let mut group_id = 0;
+            if gst_event_parse_group_id(self.gst_event() as *mut GstEvent, &mut group_id) != 0{
+                Some(group_id)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Get the timestamp and duration of a GAP event
+    pub fn parse_gap(&self) -> Option<(GstClockTime, GstClockTime)>{
+        if self.ty() != GST_EVENT_GAP{
+            return None;
+        }
+        unsafe{
+            let mut timestamp = 0;
+            let mut duration = 0;
+            gst_event_parse_gap(self.gst_event() as *mut GstEvent, &mut timestamp, &mut duration);
+            Some((timestamp, duration))
+        }
+    }
+
+    /// Get the message of a SINK_MESSAGE event
+    pub fn parse_sink_message(&self) -> Option<Message>{
+        if self.ty() != GST_EVENT_SINK_MESSAGE{
+            return None;
+        }
+        unsafe{
+            let mut msg: *mut GstMessage = ptr::null_mut();
+            gst_event_parse_sink_message(self.gst_event() as *mut GstEvent, &mut msg);
+            let message = Message::new(msg);
+            if msg != ptr::null_mut(){
+                gst_mini_object_unref(msg as *mut GstMiniObject);
+            }
+            message
+        }
+    }
+
+    pub unsafe fn gst_event(&self) -> *const GstEvent{
+        self.event.gst_miniobject() as *const GstEvent
+    }
+
+    pub unsafe fn gst_event_mut(&mut self) -> *mut GstEvent{
+        self.event.gst_miniobject_mut() as *mut GstEvent
+    }
+
+    unsafe fn make_writable_in_place(&mut self){
+        let event = ptr::read(&self.event);
+        ptr::write(&mut self.event, event.make_writable());
+    }
+}
+
+impl ::Transfer<GstEvent> for Event{
+    unsafe fn transfer(self) ->  *mut GstEvent{
+        self.event.transfer() as *mut GstEvent
+    }
+}
+
+impl Reference for Event{
+    fn reference(&self) -> Event{
+        Event{ event: self.event.reference() }
+    }
+}
+
+impl AsRef<MiniObject> for Event{
+    fn as_ref(&self) -> &MiniObject{
+        &self.event
+    }
+}
+
+impl AsMut<MiniObject> for Event{
+    fn as_mut(&mut self) -> &mut MiniObject{
+        &mut self.event
+    }
+}
+
+impl From<Event> for MiniObject{
+    fn from(e: Event) -> MiniObject{
+        e.event
+    }
+}
+
+impl Deref for Event{
+    type Target = MiniObject;
+    fn deref(&self) -> &MiniObject{
+        &self.event
+    }
+}
+
+impl DerefMut for Event{
+    fn deref_mut(&mut self) -> &mut MiniObject{
+        &mut self.event
+    }
+}
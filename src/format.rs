@@ -0,0 +1,52 @@
+use ffi::*;
+
+/// Standard predefined formats for positions, durations and seeking
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+pub enum Format{
+    /// Undefined format
+    Undefined,
+    /// The default format of the pad/element. This can be samples for raw
+    /// audio, frames/fields for raw video (some, but not all, elements
+    /// support this; use Time if you don't have a good reason to query
+    /// for samples/frames)
+    Default,
+    /// Bytes
+    Bytes,
+    /// Time in nanoseconds
+    Time,
+    /// Buffers (few, if any, elements implement this as of May 2009)
+    Buffers,
+    /// Percentage of stream (few, if any, elements implement this as of
+    /// May 2009)
+    Percent,
+    /// A format registered at runtime with gst_format_register
+    Other(GstFormat),
+}
+
+impl From<GstFormat> for Format{
+    fn from(format: GstFormat) -> Format{
+        match format{
+            GST_FORMAT_UNDEFINED => Format::Undefined,
+            GST_FORMAT_DEFAULT => Format::Default,
+            GST_FORMAT_BYTES => Format::Bytes,
+            GST_FORMAT_TIME => Format::Time,
+            GST_FORMAT_BUFFERS => Format::Buffers,
+            GST_FORMAT_PERCENT => Format::Percent,
+            other => Format::Other(other),
+        }
+    }
+}
+
+impl From<Format> for GstFormat{
+    fn from(format: Format) -> GstFormat{
+        match format{
+            Format::Undefined => GST_FORMAT_UNDEFINED,
+            Format::Default => GST_FORMAT_DEFAULT,
+            Format::Bytes => GST_FORMAT_BYTES,
+            Format::Time => GST_FORMAT_TIME,
+            Format::Buffers => GST_FORMAT_BUFFERS,
+            Format::Percent => GST_FORMAT_PERCENT,
+            Format::Other(other) => other,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use ffi::*;
+use pad::Pad;
+use object::Object;
+use reference::Reference;
+use util::*;
+use value;
+
+use std::ops::{Deref, DerefMut};
+
+/// GhostPads are useful when organizing pipelines with Bin like elements.
+/// The idea here is to create hierarchical element graphs. The bin element
+/// contains a sub-graph. Now one would like to treat the bin-element like
+/// any other Element. This is where GhostPads come into play. A GhostPad
+/// acts as a proxy for another pad. Thus the bin can have sink and source
+/// ghost-pads that are associated with sink and source pads of the child
+/// elements.
+///
+/// If the target pad is known at creation time, `GhostPad::new()` is the
+/// function to use to get a ghost-pad. Otherwise one can use
+/// `GhostPad::new_no_target()` to create the ghost-pad and use
+/// `GhostPad::set_target()` to establish the association later on.
+///
+/// Note that GhostPads add overhead to the data processing of a pipeline.
+pub struct GhostPad{
+    pad: Pad
+}
+
+impl GhostPad{
+    /// Create a new ghostpad with target as the target. The direction will
+    /// be taken from the target pad. target must be unlinked.
+    ///
+    /// Passing an empty name will let GStreamer choose a unique one.
+    pub fn new(name: &str, target: &Pad) -> Option<GhostPad>{
+        let cname = CString::new(name).unwrap();
+        unsafe{
+            let name = if name != "" {
+                cname.as_ptr()
+            } else {
+                ptr::null()
+            };
+            let pad = gst_ghost_pad_new(name, target.gst_pad() as *mut GstPad);
+            GhostPad::new_from_floating(pad)
+        }
+    }
+
+    /// Create a new ghostpad without a target with the given direction.
+    /// A target can be set on the ghostpad later with `set_target()`.
+    ///
+    /// Passing an empty name will let GStreamer choose a unique one.
+    pub fn new_no_target(name: &str, direction: GstPadDirection) -> Option<GhostPad>{
+        let cname = CString::new(name).unwrap();
+        unsafe{
+            let name = if name != "" {
+                cname.as_ptr()
+            } else {
+                ptr::null()
+            };
+            let pad = gst_ghost_pad_new_no_target(name, direction);
+            GhostPad::new_from_floating(pad)
+        }
+    }
+
+    /// Creates a GhostPad from an already existing raw pointer to a
+    /// GstGhostPad. The passed pad has to be fully referenced
+    pub unsafe fn new_from_gst_ghost_pad(pad: *mut GstGhostPad) -> Option<GhostPad>{
+        Pad::new(pad as *mut GstPad).map(|pad| GhostPad{ pad: pad })
+    }
+
+    unsafe fn new_from_floating(pad: *mut GstPad) -> Option<GhostPad>{
+        if pad != ptr::null_mut(){
+            gst_object_ref_sink(pad as gpointer);
+            GhostPad::new_from_gst_ghost_pad(pad as *mut GstGhostPad)
+        }else{
+            None
+        }
+    }
+
+    /// Get the target pad of the ghostpad, None if it has no target.
+    pub fn target(&self) -> Option<Pad>{
+        unsafe{
+            Pad::new(gst_ghost_pad_get_target(self.gst_ghost_pad() as *mut GstGhostPad))
+        }
+    }
+
+    /// Set the new target of the ghostpad. Any existing target is unlinked
+    /// and links to the new target are established. If newtarget is None
+    /// the target will be cleared.
+    ///
+    /// Returns false if the ghostpad couldn't be linked to the new target.
+    pub fn set_target(&mut self, newtarget: Option<&Pad>) -> bool{
+        unsafe{
+            let newtarget = newtarget.map(|pad| pad.gst_pad() as *mut GstPad).unwrap_or(ptr::null_mut());
+            gst_ghost_pad_set_target(self.gst_ghost_pad_mut(), newtarget) != 0
+        }
+    }
+
+    pub unsafe fn gst_ghost_pad(&self) -> *const GstGhostPad{
+        self.pad.gst_pad() as *const GstGhostPad
+    }
+
+    pub unsafe fn gst_ghost_pad_mut(&mut self) -> *mut GstGhostPad{
+        self.pad.gst_pad_mut() as *mut GstGhostPad
+    }
+}
+
+impl ::Transfer<GstPad> for GhostPad{
+    unsafe fn transfer(self) -> *mut GstPad{
+        self.pad.transfer()
+    }
+}
+
+impl Reference for GhostPad{
+    fn reference(&self) -> GhostPad{
+        GhostPad{ pad: self.pad.reference() }
+    }
+}
+
+impl ::FromGValue for GhostPad{
+    fn from_gvalue(gvalue: &GValue) -> Option<GhostPad>{
+        unsafe{
+            if value::holds(gvalue, gst_ghost_pad_get_type()){
+                let pad = g_value_get_object(gvalue);
+                if pad != ptr::null_mut(){
+                    gst_object_ref(pad);
+                }
+                GhostPad::new_from_gst_ghost_pad(pad as *mut GstGhostPad)
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl AsRef<Pad> for GhostPad{
+    fn as_ref(&self) -> &Pad{
+        &self.pad
+    }
+}
+
+impl AsMut<Pad> for GhostPad{
+    fn as_mut(&mut self) -> &mut Pad{
+        &mut self.pad
+    }
+}
+
+impl AsRef<Object> for GhostPad{
+    fn as_ref(&self) -> &Object{
+        &self.pad
+    }
+}
+
+impl From<GhostPad> for Pad{
+    fn from(b: GhostPad) -> Pad{
+        b.pad
+    }
+}
+
+impl Deref for GhostPad{
+    type Target = Pad;
+    fn deref(&self) -> &Pad{
+        &self.pad
+    }
+}
+
+impl DerefMut for GhostPad{
+    fn deref_mut(&mut self) -> &mut Pad{
+        &mut self.pad
+    }
+}
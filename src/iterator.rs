@@ -30,18 +30,25 @@ impl<Item> Iter<Item>{
 }
 
 pub enum Error{
-    Resync,
     Error,
     WrongType,
 }
 
+/// When the underlying collection is modified during iteration the
+/// iterator is resynced and iteration starts again from the beginning, so
+/// items that were already returned can be returned again.
 impl<I: ::FromGValue> Iterator for Iter<I>{
     type Item = Result<I,Error>;
 
     fn next(&mut self) -> Option<Result<I,Error>>{
         unsafe{
             let mut elem: GValue = mem::zeroed();
-            match gst_iterator_next(self.it, &mut elem){
+            let mut ret = gst_iterator_next(self.it, &mut elem);
+            while ret == GST_ITERATOR_RESYNC{
+                gst_iterator_resync(self.it);
+                ret = gst_iterator_next(self.it, &mut elem);
+            }
+            match ret{
                 GST_ITERATOR_DONE => None,
                 GST_ITERATOR_OK => {
                     let ret = match I::from_gvalue(&elem){
@@ -51,7 +58,6 @@ impl<I: ::FromGValue> Iterator for Iter<I>{
                     g_value_unset(&mut elem);
                     ret
                 },
-                GST_ITERATOR_RESYNC => Some(Err(Error::Resync)),
                 GST_ITERATOR_ERROR => Some(Err(Error::Error)),
                 _ => None
             }
@@ -5,10 +5,13 @@ pub use self::appsink::AppSink;
 pub use self::appsrc::AppSrc;
 pub use self::sample::Sample;
 pub use self::caps::Caps;
+pub use self::caps_features::CapsFeatures;
 pub use self::buffer::Buffer;
+pub use self::event::Event;
+pub use self::query::Query;
 pub use self::mapinfo::MapInfo;
 pub use self::mapinfo::Map;
-pub use self::element::Element;
+pub use self::element::{Element, RequestPad};
 pub use self::bus::Bus;
 pub use self::bin::Bin;
 pub use self::pipeline::Pipeline;
@@ -17,17 +20,27 @@ pub use self::message::Message;
 pub use self::mainloop::MainLoop;
 pub use self::error::Error;
 pub use self::error::Result;
+pub use self::clock_time::{ClockTime, ParseClockTimeError};
+pub use self::format::Format;
+pub use self::seek::{Seek, SeekFlags, SeekType};
+pub use self::segment::Segment;
+pub use self::state::{State, StateChangeSuccess, StateChangeError, CachedState};
 pub use self::videoframe::VideoFrame;
 pub use self::videoframe::VideoPlane;
 pub use self::videoframe::VideoComponent;
 pub use self::videoinfo::VideoInfo;
 pub use self::buffer_pool::BufferPool;
 pub use self::pad::Pad;
+pub use self::ghost_pad::GhostPad;
+pub use self::pad_template::PadTemplate;
 pub use self::structure::Structure;
+pub use self::tag_list::TagList;
+pub use self::toc::Toc;
+pub use self::value::Value;
 pub use self::iterator::Iter;
 pub use self::reference::{Ref, Reference};
 pub use self::miniobject::MiniObject;
-pub use self::object::Object;
+pub use self::object::{Object, SignalHandlerId};
 
 use ffi::*;
 use std::ptr;
@@ -46,8 +59,11 @@ pub mod appsink;
 /// Easy way for applications to inject buffers into a pipeline.
 mod appsrc;
 mod sample;
-mod caps;
+pub mod caps;
+pub mod caps_features;
 mod buffer;
+pub mod event;
+pub mod query;
 mod element;
 pub mod bus;
 mod bin;
@@ -56,16 +72,26 @@ mod playbin;
 mod message;
 pub mod mainloop;
 mod error;
+mod state;
+mod clock_time;
+mod format;
+mod seek;
+mod segment;
 mod videoframe;
 mod videoinfo;
 mod mapinfo;
 mod buffer_pool;
-mod pad;
-mod structure;
+pub mod pad;
+mod ghost_pad;
+mod pad_template;
+pub mod structure;
+pub mod tag_list;
+mod toc;
 mod iterator;
 mod reference;
 mod miniobject;
 mod object;
+pub mod value;
 
 #[cfg(target_os="linux")]
 mod link_linux;
@@ -120,3 +146,15 @@ pub trait Transfer<PtrType=GstElement>{
 pub trait FromGValue{
     fn from_gvalue(value: &GValue) -> Option<Self> where Self:Sized;
 }
+
+pub trait ToGValue{
+    /// Returns a newly initialized GValue holding a copy of self. The
+    /// caller is responsible for unsetting it or transferring it
+    fn to_gvalue(&self) -> GValue;
+}
+
+impl<'a, T: ToGValue> ToGValue for &'a T{
+    fn to_gvalue(&self) -> GValue{
+        (**self).to_gvalue()
+    }
+}
@@ -3,9 +3,16 @@ use util::*;
 use error::Error;
 use std::os::raw;
 use reference::Reference;
+use tag_list::TagList;
+use toc;
+use clock_time::ClockTime;
+use structure::Structure;
+use element::Element;
+use object::Object;
+use pad::Pad;
+use ::Transfer;
 
 unsafe impl Send for GstMessage {}
-unsafe impl Send for GstTagList {}
 unsafe impl Send for Message {}
 
 pub type MessagePrivate = *mut GstMessage;
@@ -15,6 +22,43 @@ unsafe fn gst_message_ref(msg: *mut GstMessage) -> *mut GstMessage{
 	gst_mini_object_ref(mem::transmute(msg)) as *mut GstMessage
 }
 
+unsafe fn raw_object(object: &Object) -> *mut GstObject{
+    object.gst_object() as *mut GstObject
+}
+
+unsafe fn ref_object(object: *mut GstObject) -> Option<Object>{
+    if object != ptr::null_mut(){
+        gst_object_ref(object as *mut raw::c_void);
+    }
+    Object::new(object)
+}
+
+unsafe fn ref_element(element: *mut GstElement) -> Option<Element>{
+    if element != ptr::null_mut(){
+        gst_object_ref(element as *mut raw::c_void);
+    }
+    Element::new_from_gst_element(element)
+}
+
+unsafe fn copy_structure(msg: *mut GstMessage) -> Option<Structure>{
+    let structure = gst_message_get_structure(msg);
+    if structure != ptr::null(){
+        Structure::new_from_gst_structure(gst_structure_copy(structure))
+    }else{
+        None
+    }
+}
+
+unsafe fn take_string(string: *mut gchar) -> String{
+    if string != ptr::null_mut(){
+        let ret = from_c_str!(string).to_string();
+        g_free(string as gpointer);
+        ret
+    }else{
+        String::new()
+    }
+}
+
 pub enum Message{
     Unknown(MessagePrivate),
     Eos(MessagePrivate),
@@ -25,38 +69,60 @@ pub enum Message{
     Info(MessagePrivate),
     InfoParsed{msg: MessagePrivate, error: Error, debug: String},
     Tag(MessagePrivate),
-    TagParsed{msg: MessagePrivate, tags: *mut GstTagList},
+    TagParsed{msg: MessagePrivate, tags: TagList},
     Buffering(MessagePrivate),
     BufferingParsed{msg: MessagePrivate, pct: i32},
     StateChanged(MessagePrivate),
     StateChangedParsed{msg: MessagePrivate, old: GstState, new: GstState, pending: GstState},
     StateDirty(MessagePrivate),
     StepDone(MessagePrivate),
+    StepDoneParsed{msg: MessagePrivate, format: GstFormat, amount: u64, rate: f64, flush: bool, intermediate: bool, duration: ClockTime, eos: bool},
     ClockProvide(MessagePrivate),
+    ClockProvideParsed{msg: MessagePrivate, clock: Object, ready: bool},
     ClockLost(MessagePrivate),
+    ClockLostParsed{msg: MessagePrivate, clock: Object},
     NewClock(MessagePrivate),
+    NewClockParsed{msg: MessagePrivate, clock: Object},
     StructureChange(MessagePrivate),
+    StructureChangeParsed{msg: MessagePrivate, ty: GstStructureChangeType, owner: Element, busy: bool},
     StreamStatus(MessagePrivate),
+    StreamStatusParsed{msg: MessagePrivate, ty: GstStreamStatusType, owner: Element},
     Application(MessagePrivate),
+    ApplicationParsed{msg: MessagePrivate, structure: Structure},
     Element(MessagePrivate),
+    ElementParsed{msg: MessagePrivate, structure: Structure},
     SegmentStart(MessagePrivate),
+    SegmentStartParsed{msg: MessagePrivate, format: GstFormat, position: i64},
     SegmentDone(MessagePrivate),
+    SegmentDoneParsed{msg: MessagePrivate, format: GstFormat, position: i64},
     DurationChanged(MessagePrivate),
     Latency(MessagePrivate),
     AsyncStart(MessagePrivate),
     AsyncDone(MessagePrivate),
+    AsyncDoneParsed{msg: MessagePrivate, running_time: ClockTime},
     RequestState(MessagePrivate),
+    RequestStateParsed{msg: MessagePrivate, state: GstState},
     StepStart(MessagePrivate),
+    StepStartParsed{msg: MessagePrivate, active: bool, format: GstFormat, amount: u64, rate: f64, flush: bool, intermediate: bool},
     Qos(MessagePrivate),
+    QosParsed{msg: MessagePrivate, live: bool, running_time: ClockTime, stream_time: ClockTime, timestamp: ClockTime, duration: ClockTime, jitter: i64, proportion: f64, quality: i32, format: GstFormat, processed: u64, dropped: u64},
     Progress(MessagePrivate),
+    ProgressParsed{msg: MessagePrivate, ty: GstProgressType, code: String, text: String},
     Toc(MessagePrivate),
+    TocParsed{msg: MessagePrivate, toc: toc::Toc, updated: bool},
     ResetTime(MessagePrivate),
+    ResetTimeParsed{msg: MessagePrivate, running_time: ClockTime},
     StreamStart(MessagePrivate),
+    StreamStartParsed{msg: MessagePrivate, group_id: Option<u32>},
     NeedContext(MessagePrivate),
+    NeedContextParsed{msg: MessagePrivate, context_type: String},
     HaveContext(MessagePrivate),
+    HaveContextParsed{msg: MessagePrivate, context_type: String},
     Extended(MessagePrivate),
     DeviceAdded(MessagePrivate),
+    DeviceAddedParsed{msg: MessagePrivate, device: Object},
     DeviceRemoved(MessagePrivate),
+    DeviceRemovedParsed{msg: MessagePrivate, device: Object},
     Any(MessagePrivate),
 }
 
@@ -109,77 +175,249 @@ impl Message{
                  GST_MESSAGE_DEVICE_ADDED => Some(Message::DeviceAdded(gst_message)),
                  GST_MESSAGE_DEVICE_REMOVED => Some(Message::DeviceRemoved(gst_message)),
                  GST_MESSAGE_ANY => Some(Message::Any(gst_message)),
-                 _ => None
+                 // types added in newer GStreamer versions
+                 _ => Some(Message::Unknown(gst_message))
             }
         }else{
             None
         }
     }
 
-    pub unsafe fn new_eos(src: *mut GstObject) -> Option<Message>{
-        Message::new(gst_message_new_eos(src))
+    /// Create a new eos message. This message is generated and posted in
+    /// the sink elements of a bin. The bin will only forward the EOS
+    /// message to the application if all sinks have posted an EOS message.
+    pub fn new_eos(src: &Object) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_eos(raw_object(src))).unwrap()
+        }
     }
 
-    pub unsafe fn new_error(src: *mut GstObject, error: *mut GError, debug: &str) -> Option<Message>{
+    /// Create a new error message. The message will copy error and debug.
+    /// This message is posted by elements when a fatal event occurred. The
+    /// pipeline will probably (partially) stop.
+    pub fn new_error(src: &Object, error: &Error, debug: &str) -> Message{
         let cdebug = CString::new(debug).unwrap();
-        Message::new(gst_message_new_error(src,error,mem::transmute(cdebug.as_ptr())))
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_error(raw_object(src), error.gst_error() as *mut GError, cdebug.as_ptr())).unwrap()
+        }
     }
 
-    pub unsafe fn new_warning(src: *mut GstObject, error: *mut GError, debug: &str) -> Option<Message>{
+    /// Create a new warning message. The message will make copies of error
+    /// and debug.
+    pub fn new_warning(src: &Object, error: &Error, debug: &str) -> Message{
         let cdebug = CString::new(debug).unwrap();
-        Message::new(gst_message_new_warning(src,error,mem::transmute(cdebug.as_ptr())))
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_warning(raw_object(src), error.gst_error() as *mut GError, cdebug.as_ptr())).unwrap()
+        }
     }
 
-    pub unsafe fn new_info(src: *mut GstObject, error: *mut GError, debug: &str) -> Option<Message>{
-		let cdebug = CString::new(debug).unwrap();
-        Message::new(gst_message_new_info(src,error,mem::transmute(cdebug.as_ptr())))
+    /// Create a new info message. The message will make copies of error
+    /// and debug.
+    pub fn new_info(src: &Object, error: &Error, debug: &str) -> Message{
+        let cdebug = CString::new(debug).unwrap();
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_info(raw_object(src), error.gst_error() as *mut GError, cdebug.as_ptr())).unwrap()
+        }
     }
 
-    pub unsafe fn new_tag(src: *mut GstObject, tag_list: *mut GstTagList) -> Option<Message>{
-        Message::new(gst_message_new_tag(src,tag_list))
+    /// Create a new tag message. The message will take ownership of the
+    /// tag list.
+    pub fn new_tag(src: &Object, tag_list: TagList) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_tag(raw_object(src), tag_list.transfer())).unwrap()
+        }
     }
 
-    pub unsafe fn new_buffering(src: *mut GstObject, pct: i32) -> Option<Message>{
-        Message::new(gst_message_new_buffering(src,pct))
+    /// Create a new buffering message. This message can be posted by an
+    /// element that needs to buffer data before it can continue processing.
+    /// percent should be a value between 0 and 100. A value of 100 means
+    /// that the buffering completed.
+    pub fn new_buffering(src: &Object, percent: i32) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_buffering(raw_object(src), percent)).unwrap()
+        }
     }
 
-    pub unsafe fn new_state_changed(src: *mut GstObject, old_state: GstState, new_state: GstState, pending: GstState) -> Option<Message>{
-        Message::new(gst_message_new_state_changed(src,old_state,new_state,pending))
+    /// Create a state change message. This message is posted whenever an
+    /// element changed its state.
+    pub fn new_state_changed(src: &Object, old: GstState, new: GstState, pending: GstState) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_state_changed(raw_object(src), old, new, pending)).unwrap()
+        }
     }
 
-    pub unsafe fn new_state_dirty(src: *mut GstObject) -> Option<Message>{
-        Message::new(gst_message_new_state_dirty(src))
+    /// Create a state dirty message. This message is posted whenever an
+    /// element changed its state asynchronously and is used internally to
+    /// update the states of container objects.
+    pub fn new_state_dirty(src: &Object) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_state_dirty(raw_object(src))).unwrap()
+        }
     }
 
-    pub unsafe fn new_step_done(src: *mut GstObject, format: GstFormat,
+    /// This message is posted by elements when they complete a part, when
+    /// intermediate is true, or the complete step operation.
+    ///
+    /// duration will contain the amount of time (in GST_FORMAT_TIME) of
+    /// the stepped amount of media in format format.
+    pub fn new_step_done<T: Into<ClockTime>>(src: &Object, format: GstFormat,
                          amount: u64, rate: f64,
                          flush: bool, intermediate: bool,
-                         duration: u64, eos: bool) -> Option<Message>{
-        Message::new(gst_message_new_step_done(src,format,amount,rate,flush as i32,intermediate as i32,duration,eos as i32))
+                         duration: T, eos: bool) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_step_done(raw_object(src),format,amount,rate,flush as i32,intermediate as i32,duration.into().into(),eos as i32)).unwrap()
+        }
     }
 
-    pub unsafe fn new_clock_provide(src: *mut GstObject, clock: *mut GstClock, ready: bool) -> Option<Message>{
-        Message::new(gst_message_new_clock_provide(src,clock,ready as i32))
+    /// Create a clock provide message. This message is posted whenever an
+    /// element is ready to provide a clock or lost its ability to provide
+    /// a clock (maybe because it paused or became EOS).
+    ///
+    /// This message is mainly used internally to manage the clock
+    /// selection. clock is the GstClock the element provides.
+    pub fn new_clock_provide(src: &Object, clock: &Object, ready: bool) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_clock_provide(raw_object(src), raw_object(clock) as *mut GstClock, ready as i32)).unwrap()
+        }
     }
 
-    pub unsafe fn new_clock_lost(src: *mut GstObject, clock: *mut GstClock) -> Option<Message>{
-        Message::new(gst_message_new_clock_lost(src,clock))
+    /// Create a clock lost message. This message is posted whenever the
+    /// clock is not valid anymore.
+    ///
+    /// If this message is posted by the pipeline, the pipeline will select
+    /// a new clock again when it goes to PLAYING. It might therefore be
+    /// needed to set the pipeline to PAUSED and PLAYING again.
+    pub fn new_clock_lost(src: &Object, clock: &Object) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_clock_lost(raw_object(src), raw_object(clock) as *mut GstClock)).unwrap()
+        }
     }
 
-    pub unsafe fn new_new_clock(src: *mut GstObject, clock: *mut GstClock) -> Option<Message>{
-        Message::new(gst_message_new_new_clock(src,clock))
+    /// Create a new clock message. This message is posted whenever the
+    /// pipeline selects a new clock for the pipeline.
+    pub fn new_new_clock(src: &Object, clock: &Object) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_new_clock(raw_object(src), raw_object(clock) as *mut GstClock)).unwrap()
+        }
     }
 
-    pub unsafe fn new_application(src: *mut GstObject, structure: *mut GstStructure) -> Option<Message>{
-        Message::new(gst_message_new_application(src,structure))
+    /// Create a new duration changed message. This message is posted by
+    /// elements that know the duration of a stream when the duration
+    /// changes. Applications should query the new duration.
+    pub fn new_duration_changed(src: &Object) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_duration_changed(raw_object(src))).unwrap()
+        }
     }
 
-    pub unsafe fn new_element(src: *mut GstObject, structure: *mut GstStructure) -> Option<Message>{
-        Message::new(gst_message_new_element(src,structure))
+    /// This message can be posted by elements when their latency
+    /// requirements have changed.
+    pub fn new_latency(src: &Object) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_latency(raw_object(src))).unwrap()
+        }
     }
 
-    pub unsafe fn new_custom(ty: GstMessageType, src: *mut GstObject, structure: *mut GstStructure) -> Option<Message>{
-        Message::new(gst_message_new_custom(ty,src,structure))
+    /// This message is posted by elements when they start an ASYNC state
+    /// change.
+    pub fn new_async_start(src: &Object) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_async_start(raw_object(src))).unwrap()
+        }
+    }
+
+    /// The message is posted when elements completed an ASYNC state change.
+    /// running_time contains the time of the desired running_time when this
+    /// elements goes to PLAYING.
+    pub fn new_async_done<T: Into<ClockTime>>(src: &Object, running_time: T) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_async_done(raw_object(src), running_time.into().into())).unwrap()
+        }
+    }
+
+    /// This message can be posted by elements when they want to have their
+    /// state changed. A typical use case would be an audio server that
+    /// wants to pause the pipeline because a higher priority stream is
+    /// being played.
+    pub fn new_request_state(src: &Object, state: GstState) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_request_state(raw_object(src), state)).unwrap()
+        }
+    }
+
+    /// Create a new segment message. This message is posted by elements
+    /// that start playback of a segment as a result of a segment seek.
+    pub fn new_segment_start(src: &Object, format: GstFormat, position: i64) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_segment_start(raw_object(src), format, position)).unwrap()
+        }
+    }
+
+    /// Create a new segment done message. This message is posted by
+    /// elements that finish playback of a segment as a result of a segment
+    /// seek.
+    pub fn new_segment_done(src: &Object, format: GstFormat, position: i64) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_segment_done(raw_object(src), format, position)).unwrap()
+        }
+    }
+
+    /// Create a new stream_start message. This message is generated and
+    /// posted in the sink elements of a bin when it received a stream-start
+    /// event.
+    pub fn new_stream_start(src: &Object) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_stream_start(raw_object(src))).unwrap()
+        }
+    }
+
+    /// Progress messages are posted by elements when they use an
+    /// asynchronous task to perform actions triggered by a state change.
+    ///
+    /// code contains a well defined string describing the action. text
+    /// should contain a user visible string detailing the current action.
+    pub fn new_progress(src: &Object, ty: GstProgressType, code: &str, text: &str) -> Message{
+        let ccode = CString::new(code).unwrap();
+        let ctext = CString::new(text).unwrap();
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_progress(raw_object(src), ty, ccode.as_ptr(), ctext.as_ptr())).unwrap()
+        }
+    }
+
+    /// Create a new application-typed message. GStreamer will never create
+    /// these messages; they are a gift from us to you. Enjoy.
+    pub fn new_application(src: &Object, structure: Structure) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_application(raw_object(src), structure.transfer())).unwrap()
+        }
+    }
+
+    /// Create a new element-specific message. This is meant as a generic
+    /// way of allowing one-way communication from an element to an
+    /// application, for example "the firewire cable was unplugged".
+    pub fn new_element(src: &Object, structure: Structure) -> Message{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_element(raw_object(src), structure.transfer())).unwrap()
+        }
+    }
+
+    /// Create a new custom-typed message. This can be used for anything
+    /// not handled by other message-specific functions to pass a message
+    /// to the app. Returns None if the message type is unknown.
+    pub fn new_custom(ty: GstMessageType, src: &Object, structure: Structure) -> Option<Message>{
+        unsafe{
+            Message::new_from_gst_message(gst_message_new_custom(ty, raw_object(src), structure.transfer()))
+        }
+    }
+
+    /// Creates a Message from a GstMessage, taking ownership of it. Unlike
+    /// `new` this doesn't take an additional reference.
+    pub unsafe fn new_from_gst_message(gst_message: *mut GstMessage) -> Option<Message>{
+        let message = Message::new(gst_message);
+        if gst_message != ptr::null_mut(){
+            gst_mini_object_unref(gst_message as *mut GstMiniObject);
+        }
+        message
     }
 
 	#[allow(unused_variables)]
@@ -201,31 +439,53 @@ impl Message{
             Message::StateChangedParsed{msg, ref old, ref new, ref pending} => msg,
             Message::StateDirty(msg) => msg,
             Message::StepDone(msg) => msg,
+            Message::StepDoneParsed{msg, ..} => msg,
             Message::ClockProvide(msg) => msg,
+            Message::ClockProvideParsed{msg, ..} => msg,
             Message::ClockLost(msg) => msg,
+            Message::ClockLostParsed{msg, ..} => msg,
             Message::NewClock(msg) => msg,
+            Message::NewClockParsed{msg, ..} => msg,
             Message::StructureChange(msg) => msg,
+            Message::StructureChangeParsed{msg, ..} => msg,
             Message::StreamStatus(msg) => msg,
+            Message::StreamStatusParsed{msg, ..} => msg,
             Message::Application(msg) => msg,
+            Message::ApplicationParsed{msg, ..} => msg,
             Message::Element(msg) => msg,
+            Message::ElementParsed{msg, ..} => msg,
             Message::SegmentStart(msg) => msg,
+            Message::SegmentStartParsed{msg, ..} => msg,
             Message::SegmentDone(msg) => msg,
+            Message::SegmentDoneParsed{msg, ..} => msg,
             Message::DurationChanged(msg) => msg,
             Message::Latency(msg) => msg,
             Message::AsyncStart(msg) => msg,
             Message::AsyncDone(msg) => msg,
+            Message::AsyncDoneParsed{msg, ..} => msg,
             Message::RequestState(msg) => msg,
+            Message::RequestStateParsed{msg, ..} => msg,
             Message::StepStart(msg) => msg,
+            Message::StepStartParsed{msg, ..} => msg,
             Message::Qos(msg) => msg,
+            Message::QosParsed{msg, ..} => msg,
             Message::Progress(msg) => msg,
+            Message::ProgressParsed{msg, ..} => msg,
             Message::Toc(msg) => msg,
+            Message::TocParsed{msg, ..} => msg,
             Message::ResetTime(msg) => msg,
+            Message::ResetTimeParsed{msg, ..} => msg,
             Message::StreamStart(msg) => msg,
+            Message::StreamStartParsed{msg, ..} => msg,
             Message::NeedContext(msg) => msg,
+            Message::NeedContextParsed{msg, ..} => msg,
             Message::HaveContext(msg) => msg,
+            Message::HaveContextParsed{msg, ..} => msg,
             Message::Extended(msg) => msg,
             Message::DeviceAdded(msg) => msg,
+            Message::DeviceAddedParsed{msg, ..} => msg,
             Message::DeviceRemoved(msg) => msg,
+            Message::DeviceRemovedParsed{msg, ..} => msg,
             Message::Any(msg) => msg,
         }
     }
@@ -249,31 +509,53 @@ impl Message{
             Message::StateChangedParsed{msg, ref old, ref new, ref pending} => msg,
             Message::StateDirty(msg) => msg,
             Message::StepDone(msg) => msg,
+            Message::StepDoneParsed{msg, ..} => msg,
             Message::ClockProvide(msg) => msg,
+            Message::ClockProvideParsed{msg, ..} => msg,
             Message::ClockLost(msg) => msg,
+            Message::ClockLostParsed{msg, ..} => msg,
             Message::NewClock(msg) => msg,
+            Message::NewClockParsed{msg, ..} => msg,
             Message::StructureChange(msg) => msg,
+            Message::StructureChangeParsed{msg, ..} => msg,
             Message::StreamStatus(msg) => msg,
+            Message::StreamStatusParsed{msg, ..} => msg,
             Message::Application(msg) => msg,
+            Message::ApplicationParsed{msg, ..} => msg,
             Message::Element(msg) => msg,
+            Message::ElementParsed{msg, ..} => msg,
             Message::SegmentStart(msg) => msg,
+            Message::SegmentStartParsed{msg, ..} => msg,
             Message::SegmentDone(msg) => msg,
+            Message::SegmentDoneParsed{msg, ..} => msg,
             Message::DurationChanged(msg) => msg,
             Message::Latency(msg) => msg,
             Message::AsyncStart(msg) => msg,
             Message::AsyncDone(msg) => msg,
+            Message::AsyncDoneParsed{msg, ..} => msg,
             Message::RequestState(msg) => msg,
+            Message::RequestStateParsed{msg, ..} => msg,
             Message::StepStart(msg) => msg,
+            Message::StepStartParsed{msg, ..} => msg,
             Message::Qos(msg) => msg,
+            Message::QosParsed{msg, ..} => msg,
             Message::Progress(msg) => msg,
+            Message::ProgressParsed{msg, ..} => msg,
             Message::Toc(msg) => msg,
+            Message::TocParsed{msg, ..} => msg,
             Message::ResetTime(msg) => msg,
+            Message::ResetTimeParsed{msg, ..} => msg,
             Message::StreamStart(msg) => msg,
+            Message::StreamStartParsed{msg, ..} => msg,
             Message::NeedContext(msg) => msg,
+            Message::NeedContextParsed{msg, ..} => msg,
             Message::HaveContext(msg) => msg,
+            Message::HaveContextParsed{msg, ..} => msg,
             Message::Extended(msg) => msg,
             Message::DeviceAdded(msg) => msg,
+            Message::DeviceAddedParsed{msg, ..} => msg,
             Message::DeviceRemoved(msg) => msg,
+            Message::DeviceRemovedParsed{msg, ..} => msg,
             Message::Any(msg) => msg,
         }
     }
@@ -308,16 +590,77 @@ impl Message{
         }
     }
 
-    pub unsafe fn src(&self) -> *mut GstObject{
-        (*self.gst_message()).src
+    /// Returns the object that posted the message or None if the message
+    /// has no source.
+    pub fn src(&self) -> Option<Object>{
+        unsafe{
+            ref_object(self.gst_src())
+        }
+    }
+
+    /// Returns the source of the message as an Element or None if the
+    /// message has no source or it wasn't posted by an element.
+    pub fn src_element(&self) -> Option<Element>{
+        unsafe{
+            let src = self.gst_src();
+            if src != ptr::null_mut() && g_type_check_instance_is_a(src as *mut GTypeInstance, gst_element_get_type()) != 0{
+                ref_element(src as *mut GstElement)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Returns the source of the message as a Pad or None if the message
+    /// has no source or it wasn't posted by a pad.
+    pub fn src_pad(&self) -> Option<Pad>{
+        unsafe{
+            let src = self.gst_src();
+            if src != ptr::null_mut() && g_type_check_instance_is_a(src as *mut GTypeInstance, gst_pad_get_type()) != 0{
+                gst_object_ref(src as *mut raw::c_void);
+                Pad::new(src as *mut GstPad)
+            }else{
+                None
+            }
+        }
     }
 
     pub fn src_name(&self) -> String{
         unsafe{
-            from_c_str!(mem::transmute((*self.src()).name)).to_string()
+            let src = self.gst_src();
+            if src != ptr::null_mut(){
+                from_c_str!(mem::transmute((*src).name)).to_string()
+            }else{
+                "".to_string()
+            }
+        }
+    }
+
+    /// Returns the full path of the source of the message in the object
+    /// hierarchy, like "/pipeline0/decodebin0/queue1", which unlike the
+    /// name is unique within a pipeline.
+    pub fn src_path(&self) -> Option<String>{
+        unsafe{
+            let src = self.gst_src();
+            if src != ptr::null_mut(){
+                Some(take_string(gst_object_get_path_string(src)))
+            }else{
+                None
+            }
         }
     }
 
+    /// Returns true if the message was posted by object.
+    pub fn is_from(&self, object: &Object) -> bool{
+        unsafe{
+            self.gst_src() as *const GstObject == object.gst_object()
+        }
+    }
+
+    pub unsafe fn gst_src(&self) -> *mut GstObject{
+        (*self.gst_message()).src
+    }
+
     pub unsafe fn structure(&self) -> *const GstStructure{
         gst_message_get_structure(mem::transmute(self.gst_message()))
     }
@@ -334,6 +677,10 @@ impl Message{
         }
     }
 
+    /// Returns a copy of the message with its contents parsed
+    /// into the corresponding `*Parsed` variant so they can be matched on.
+    /// Messages that carry no data, like Eos, StateDirty, DurationChanged,
+    /// Latency or AsyncStart, are returned unparsed.
     pub fn parse(&self) -> Message{
         unsafe{
 			let ret = Message::new(gst_mini_object_copy(self.gst_message() as *mut GstMiniObject) as *const GstMessage).unwrap();
@@ -369,7 +716,10 @@ impl Message{
                     let mut tags: *mut GstTagList = ptr::null_mut();
                     gst_message_parse_tag(message,&mut tags);
                     let message = gst_message_ref(message);
-                    Message::TagParsed{msg: message, tags: tags}
+                    match TagList::new(tags){
+                        Some(tags) => Message::TagParsed{msg: message, tags: tags},
+                        None => Message::Tag(message)
+                    }
                 }
                 Message::Buffering(message) => {
                     let mut pct: i32 = 0;
@@ -385,6 +735,210 @@ impl Message{
                     let message = gst_message_ref(message);
                     Message::StateChangedParsed{msg: message, old: old, new: new, pending: pending}
                 }
+                Message::StepDone(message) => {
+                    let mut format: GstFormat = GST_FORMAT_UNDEFINED;
+                    let mut amount: u64 = 0;
+                    let mut rate: f64 = 0.0;
+                    let mut flush: gboolean = 0;
+                    let mut intermediate: gboolean = 0;
+                    let mut duration: u64 = 0;
+                    let mut eos: gboolean = 0;
+                    gst_message_parse_step_done(message,&mut format,&mut amount,&mut rate,&mut flush,&mut intermediate,&mut duration,&mut eos);
+                    let message = gst_message_ref(message);
+                    Message::StepDoneParsed{msg: message, format: format, amount: amount, rate: rate, flush: flush != 0, intermediate: intermediate != 0, duration: duration.into(), eos: eos != 0}
+                }
+                Message::ClockProvide(message) => {
+                    let mut clock: *mut GstClock = ptr::null_mut();
+                    let mut ready: gboolean = 0;
+                    gst_message_parse_clock_provide(message,&mut clock,&mut ready);
+                    let message = gst_message_ref(message);
+                    match ref_object(clock as *mut GstObject){
+                        Some(clock) => Message::ClockProvideParsed{msg: message, clock: clock, ready: ready != 0},
+                        None => Message::ClockProvide(message)
+                    }
+                }
+                Message::ClockLost(message) => {
+                    let mut clock: *mut GstClock = ptr::null_mut();
+                    gst_message_parse_clock_lost(message,&mut clock);
+                    let message = gst_message_ref(message);
+                    match ref_object(clock as *mut GstObject){
+                        Some(clock) => Message::ClockLostParsed{msg: message, clock: clock},
+                        None => Message::ClockLost(message)
+                    }
+                }
+                Message::NewClock(message) => {
+                    let mut clock: *mut GstClock = ptr::null_mut();
+                    gst_message_parse_new_clock(message,&mut clock);
+                    let message = gst_message_ref(message);
+                    match ref_object(clock as *mut GstObject){
+                        Some(clock) => Message::NewClockParsed{msg: message, clock: clock},
+                        None => Message::NewClock(message)
+                    }
+                }
+                Message::StructureChange(message) => {
+                    let mut ty: GstStructureChangeType = GST_STRUCTURE_CHANGE_TYPE_PAD_LINK;
+                    let mut owner: *mut GstElement = ptr::null_mut();
+                    let mut busy: gboolean = 0;
+                    gst_message_parse_structure_change(message,&mut ty,&mut owner,&mut busy);
+                    let message = gst_message_ref(message);
+                    match ref_element(owner){
+                        Some(owner) => Message::StructureChangeParsed{msg: message, ty: ty, owner: owner, busy: busy != 0},
+                        None => Message::StructureChange(message)
+                    }
+                }
+                Message::StreamStatus(message) => {
+                    let mut ty: GstStreamStatusType = GST_STREAM_STATUS_TYPE_CREATE;
+                    let mut owner: *mut GstElement = ptr::null_mut();
+                    gst_message_parse_stream_status(message,&mut ty,&mut owner);
+                    let message = gst_message_ref(message);
+                    match ref_element(owner){
+                        Some(owner) => Message::StreamStatusParsed{msg: message, ty: ty, owner: owner},
+                        None => Message::StreamStatus(message)
+                    }
+                }
+                Message::Application(message) => {
+                    let structure = copy_structure(message);
+                    let message = gst_message_ref(message);
+                    match structure{
+                        Some(structure) => Message::ApplicationParsed{msg: message, structure: structure},
+                        None => Message::Application(message)
+                    }
+                }
+                Message::Element(message) => {
+                    let structure = copy_structure(message);
+                    let message = gst_message_ref(message);
+                    match structure{
+                        Some(structure) => Message::ElementParsed{msg: message, structure: structure},
+                        None => Message::Element(message)
+                    }
+                }
+                Message::SegmentStart(message) => {
+                    let mut format: GstFormat = GST_FORMAT_UNDEFINED;
+                    let mut position: i64 = 0;
+                    gst_message_parse_segment_start(message,&mut format,&mut position);
+                    let message = gst_message_ref(message);
+                    Message::SegmentStartParsed{msg: message, format: format, position: position}
+                }
+                Message::SegmentDone(message) => {
+                    let mut format: GstFormat = GST_FORMAT_UNDEFINED;
+                    let mut position: i64 = 0;
+                    gst_message_parse_segment_done(message,&mut format,&mut position);
+                    let message = gst_message_ref(message);
+                    Message::SegmentDoneParsed{msg: message, format: format, position: position}
+                }
+                Message::AsyncDone(message) => {
+                    let mut running_time: GstClockTime = 0;
+                    gst_message_parse_async_done(message,&mut running_time);
+                    let message = gst_message_ref(message);
+                    Message::AsyncDoneParsed{msg: message, running_time: running_time.into()}
+                }
+                Message::RequestState(message) => {
+                    let mut state: GstState = GST_STATE_NULL;
+                    gst_message_parse_request_state(message,&mut state);
+                    let message = gst_message_ref(message);
+                    Message::RequestStateParsed{msg: message, state: state}
+                }
+                Message::StepStart(message) => {
+                    let mut active: gboolean = 0;
+                    let mut format: GstFormat = GST_FORMAT_UNDEFINED;
+                    let mut amount: u64 = 0;
+                    let mut rate: f64 = 0.0;
+                    let mut flush: gboolean = 0;
+                    let mut intermediate: gboolean = 0;
+                    gst_message_parse_step_start(message,&mut active,&mut format,&mut amount,&mut rate,&mut flush,&mut intermediate);
+                    let message = gst_message_ref(message);
+                    Message::StepStartParsed{msg: message, active: active != 0, format: format, amount: amount, rate: rate, flush: flush != 0, intermediate: intermediate != 0}
+                }
+                Message::Qos(message) => {
+                    let mut live: gboolean = 0;
+                    let mut running_time: u64 = 0;
+                    let mut stream_time: u64 = 0;
+                    let mut timestamp: u64 = 0;
+                    let mut duration: u64 = 0;
+                    let mut jitter: i64 = 0;
+                    let mut proportion: f64 = 0.0;
+                    let mut quality: i32 = 0;
+                    let mut format: GstFormat = GST_FORMAT_UNDEFINED;
+                    let mut processed: u64 = 0;
+                    let mut dropped: u64 = 0;
+                    gst_message_parse_qos(message,&mut live,&mut running_time,&mut stream_time,&mut timestamp,&mut duration);
+                    gst_message_parse_qos_values(message,&mut jitter,&mut proportion,&mut quality);
+                    gst_message_parse_qos_stats(message,&mut format,&mut processed,&mut dropped);
+                    let message = gst_message_ref(message);
+                    Message::QosParsed{msg: message, live: live != 0, running_time: running_time.into(), stream_time: stream_time.into(),
+                        timestamp: timestamp.into(), duration: duration.into(), jitter: jitter, proportion: proportion, quality: quality,
+                        format: format, processed: processed, dropped: dropped}
+                }
+                Message::Progress(message) => {
+                    let mut ty: GstProgressType = GST_PROGRESS_TYPE_START;
+                    let mut code: *mut gchar = ptr::null_mut();
+                    let mut text: *mut gchar = ptr::null_mut();
+                    gst_message_parse_progress(message,&mut ty,&mut code,&mut text);
+                    let message = gst_message_ref(message);
+                    Message::ProgressParsed{msg: message, ty: ty, code: take_string(code), text: take_string(text)}
+                }
+                Message::Toc(message) => {
+                    let mut toc: *mut GstToc = ptr::null_mut();
+                    let mut updated: gboolean = 0;
+                    gst_message_parse_toc(message,&mut toc,&mut updated);
+                    let message = gst_message_ref(message);
+                    match toc::Toc::new(toc){
+                        Some(toc) => Message::TocParsed{msg: message, toc: toc, updated: updated != 0},
+                        None => Message::Toc(message)
+                    }
+                }
+                Message::ResetTime(message) => {
+                    let mut running_time: GstClockTime = 0;
+                    gst_message_parse_reset_time(message,&mut running_time);
+                    let message = gst_message_ref(message);
+                    Message::ResetTimeParsed{msg: message, running_time: running_time.into()}
+                }
+                Message::StreamStart(message) => {
+                    let mut group_id: u32 = 0;
+                    let has_group_id = gst_message_parse_group_id(message,&mut group_id) != 0;
+                    let message = gst_message_ref(message);
+                    Message::StreamStartParsed{msg: message, group_id: if has_group_id { Some(group_id) } else { None }}
+                }
+                Message::NeedContext(message) => {
+                    let mut context_type: *const gchar = ptr::null();
+                    gst_message_parse_context_type(message,&mut context_type);
+                    let message = gst_message_ref(message);
+                    if context_type != ptr::null(){
+                        Message::NeedContextParsed{msg: message, context_type: from_c_str!(context_type).to_string()}
+                    }else{
+                        Message::NeedContext(message)
+                    }
+                }
+                Message::HaveContext(message) => {
+                    let mut context: *mut GstContext = ptr::null_mut();
+                    gst_message_parse_have_context(message,&mut context);
+                    let message = gst_message_ref(message);
+                    if context != ptr::null_mut(){
+                        let context_type = from_c_str!(gst_context_get_context_type(context)).to_string();
+                        gst_mini_object_unref(context as *mut GstMiniObject);
+                        Message::HaveContextParsed{msg: message, context_type: context_type}
+                    }else{
+                        Message::HaveContext(message)
+                    }
+                }
+                Message::DeviceAdded(message) => {
+                    let mut device: *mut GstDevice = ptr::null_mut();
+                    gst_message_parse_device_added(message,&mut device);
+                    let message = gst_message_ref(message);
+                    match Object::new(device as *mut GstObject){
+                        Some(device) => Message::DeviceAddedParsed{msg: message, device: device},
+                        None => Message::DeviceAdded(message)
+                    }
+                }
+                Message::DeviceRemoved(message) => {
+                    let mut device: *mut GstDevice = ptr::null_mut();
+                    gst_message_parse_device_removed(message,&mut device);
+                    let message = gst_message_ref(message);
+                    match Object::new(device as *mut GstObject){
+                        Some(device) => Message::DeviceRemovedParsed{msg: message, device: device},
+                        None => Message::DeviceRemoved(message)
+                    }
+                }
                 _ => {
                     ret
                 }
@@ -50,9 +50,11 @@ impl MiniObject{
     }
 
     pub fn make_writable(self) -> MiniObject{
+        let miniobject = self.miniobject;
+        mem::forget(self);
         unsafe{
             MiniObject{
-                miniobject: gst_mini_object_make_writable(self.miniobject)
+                miniobject: gst_mini_object_make_writable(miniobject)
             }
         }
     }
@@ -1,6 +1,10 @@
 use ffi::*;
 use util::*;
 use reference::{Reference, Ref};
+use value::{self, Value};
+use ::{FromGValue, ToGValue};
+
+use std::slice;
 
 use std::os::raw::{c_void, c_char};
 
@@ -9,6 +13,8 @@ pub struct Object{
 }
 
 
+unsafe impl Send for Object {}
+
 impl Drop for Object{
 	fn drop(&mut self){
 		unsafe{
@@ -160,14 +166,127 @@ impl Object{
 
     pub fn get<T>(&self, name: &str) -> T
     	where T: FromProperty {
+        T::get_from(name, self)
+    }
+
+    /// Returns the value of the property with the given name as a
+    /// dynamically typed Value or None if the object has no such property
+    pub fn get_value(&self, name: &str) -> Option<Value>{
+        let cname = CString::new(name).unwrap();
         unsafe{
-            let cname = CString::new(name).unwrap();
-            let mut value = mem::uninitialized();
-            g_object_get(self.gst_object() as *mut c_void, cname.as_ptr(), &mut value);
-            T::from_property(value)
+            let class = (*(self.object as *mut GTypeInstance)).g_class as *mut GObjectClass;
+            let pspec = g_object_class_find_property(class, cname.as_ptr());
+            if pspec == ptr::null_mut(){
+                return None;
+            }
+            let mut gvalue = value::new_gvalue((*pspec).value_type);
+            g_object_get_property(self.object as *mut GObject, cname.as_ptr(), &mut gvalue);
+            Some(Value::new_from_gvalue(gvalue))
+        }
+    }
+
+    /// Sets the property with the given name from a dynamically typed
+    /// Value. The value is converted to the property type if possible.
+    pub fn set_value(&mut self, name: &str, value: &Value){
+        let cname = CString::new(name).unwrap();
+        unsafe{
+            g_object_set_property(self.object as *mut GObject, cname.as_ptr(), value.gvalue());
+        }
+    }
+
+    /// Connects a closure to the signal with the given name. The closure
+    /// receives the signal arguments, starting with the object that emitted
+    /// the signal, and returns the signal's return value, if it has one.
+    ///
+    /// The closure is called from the thread that emits the signal, which
+    /// for most GStreamer signals is a streaming thread, possibly from
+    /// several threads at the same time, so it has to be Sync. It's
+    /// dropped when the handler is disconnected or the object is
+    /// destroyed.
+    ///
+    /// Returns None if the object has no such signal.
+    ///
+    /// ```ignore
+    /// decodebin.connect("pad-added", move |args| {
+    ///     let pad: Option<Pad> = args[1].get();
+    ///     None
+    /// });
+    /// ```
+    pub fn connect<F>(&mut self, signal: &str, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&[Value]) -> Option<Value> + Send + Sync + 'static{
+        self.connect_closure(signal, callback, false)
+    }
+
+    /// Like `connect` but the closure is called after the default handler
+    /// of the signal.
+    pub fn connect_after<F>(&mut self, signal: &str, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&[Value]) -> Option<Value> + Send + Sync + 'static{
+        self.connect_closure(signal, callback, true)
+    }
+
+    /// Calls callback every time the property with the given name changes
+    /// on the object.
+    pub fn on_notify<F>(&mut self, property: &str, callback: F) -> Option<SignalHandlerId>
+        where F: Fn(&Object) + Send + Sync + 'static{
+        self.connect(&format!("notify::{}", property), move |args| {
+            if let Some(object) = args.get(0).and_then(|arg| arg.get::<Object>()){
+                callback(&object);
+            }
+            None
+        })
+    }
+
+    fn connect_closure<F>(&mut self, signal: &str, callback: F, after: bool) -> Option<SignalHandlerId>
+        where F: Fn(&[Value]) -> Option<Value> + Send + Sync + 'static{
+        let csignal = CString::new(signal).unwrap();
+        unsafe{
+            let callback: Box<SignalCallback> = Box::new(callback);
+            let data = Box::into_raw(Box::new(callback)) as gpointer;
+            let closure = g_closure_new_simple(mem::size_of::<GClosure>() as guint, data);
+            g_closure_set_marshal(closure, Some(signal_marshal));
+            g_closure_add_finalize_notifier(closure, data, Some(signal_finalize));
+            let id = g_signal_connect_closure(self.object as gpointer, csignal.as_ptr(), closure, after as gboolean);
+            if id != 0{
+                Some(SignalHandlerId(id))
+            }else{
+                g_closure_ref(closure);
+                g_closure_sink(closure);
+                g_closure_unref(closure);
+                None
+            }
+        }
+    }
+
+    /// Disconnects a handler connected with `connect`, dropping its closure.
+    pub fn disconnect(&mut self, handler_id: SignalHandlerId){
+        unsafe{
+            g_signal_handler_disconnect(self.object as gpointer, handler_id.0);
+        }
+    }
+
+    /// Blocks a handler so it will not be called during any signal
+    /// emissions until it's unblocked again.
+    pub fn block_signal(&mut self, handler_id: SignalHandlerId){
+        unsafe{
+            g_signal_handler_block(self.object as gpointer, handler_id.0);
+        }
+    }
+
+    /// Undoes the effect of a previous `block_signal` call.
+    pub fn unblock_signal(&mut self, handler_id: SignalHandlerId){
+        unsafe{
+            g_signal_handler_unblock(self.object as gpointer, handler_id.0);
         }
     }
 
+    /// Returns true if the handler is still connected to the object.
+    pub fn is_signal_connected(&self, handler_id: SignalHandlerId) -> bool{
+        unsafe{
+            g_signal_handler_is_connected(self.object as gpointer, handler_id.0) != 0
+        }
+    }
+
+    #[deprecated(note="use the safe Object::connect instead")]
     pub unsafe fn signal_connect<T>(&mut self, signal: &str, callback: GCallback, data: &mut T)
         where Self:Sized{
         let csignal = CString::new(signal).unwrap();
@@ -183,6 +302,65 @@ impl Object{
     }
 }
 
+impl FromGValue for Object{
+    fn from_gvalue(gvalue: &GValue) -> Option<Object>{
+        unsafe{
+            if value::holds(gvalue, gst_object_get_type()){
+                let object = g_value_get_object(gvalue);
+                if object != ptr::null_mut(){
+                    gst_object_ref(object);
+                }
+                Object::new(object as *mut GstObject)
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for Object{
+    fn to_gvalue(&self) -> GValue{
+        let gtype = unsafe{ (*(*(self.object as *mut GTypeInstance)).g_class).g_type };
+        let mut gvalue = value::new_gvalue(gtype);
+        unsafe{ g_value_set_object(&mut gvalue, self.object as gpointer) };
+        gvalue
+    }
+}
+
+/// Identifies a signal handler connected with `Object::connect`
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub struct SignalHandlerId(gulong);
+
+trait SignalCallback: Send + Sync{
+    fn call(&self, args: &[Value]) -> Option<Value>;
+}
+
+impl<F: Fn(&[Value]) -> Option<Value> + Send + Sync> SignalCallback for F{
+    fn call(&self, args: &[Value]) -> Option<Value>{
+        self(args)
+    }
+}
+
+extern "C" fn signal_marshal(closure: *mut GClosure, return_value: *mut GValue, n_param_values: guint, param_values: *const GValue, _invocation_hint: gpointer, _marshal_data: gpointer){
+    unsafe{
+        let callback = &*((*closure).data as *const Box<SignalCallback>);
+        let args: Vec<Value> = slice::from_raw_parts(param_values, n_param_values as usize).iter()
+            .filter_map(|arg| Value::from_gvalue(arg))
+            .collect();
+        if let Some(ret) = callback.call(&args){
+            if return_value != ptr::null_mut() && (*return_value).g_type != 0{
+                g_value_transform(ret.gvalue(), return_value);
+            }
+        }
+    }
+}
+
+extern "C" fn signal_finalize(data: gpointer, _closure: *mut GClosure){
+    unsafe{
+        drop(Box::from_raw(data as *mut Box<SignalCallback>));
+    }
+}
+
 impl Reference for Object{
     fn reference(&self) -> Object{
         unsafe{ gst_object_ref(self.object as *mut c_void) };
@@ -203,8 +381,18 @@ pub trait Property{
     fn set_to(&self, key: &str, e: &mut Object);
 }
 
-pub trait FromProperty: Property{
+pub trait FromProperty: Property + Sized{
     fn from_property(t: <Self as Property>::Target) -> Self;
+
+    #[inline]
+    fn get_from(key: &str, e: &Object) -> Self{
+        unsafe{
+            let cname = CString::new(key).unwrap();
+            let mut value = mem::uninitialized();
+            g_object_get(e.gst_object() as *mut c_void, cname.as_ptr(), &mut value);
+            Self::from_property(value)
+        }
+    }
 }
 
 impl<'a> Property for &'a str{
@@ -1,10 +1,19 @@
 use ffi::*;
+use buffer::Buffer;
 use caps::Caps;
+use element::Element;
+use event::Event;
+use pad_template::PadTemplate;
+use query::Query;
 use reference::Reference;
 use object::Object;
+use value;
+use ::Transfer;
 
 use std::ptr;
 use std::mem;
+use std::marker::PhantomData;
+use std::vec;
 use std::ops::{Deref, DerefMut};
 
 pub struct Pad{
@@ -22,6 +31,48 @@ pub enum LinkReturn{
     Refused = GST_PAD_LINK_REFUSED as isize,
 }
 
+/// GST_PAD_PROBE_HANDLED, available since GStreamer 1.10 but missing
+/// from the generated bindings
+const GST_PAD_PROBE_HANDLED: GstPadProbeReturn = 4;
+
+/// Different return values for a pad probe callback.
+#[repr(u32)]
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum ProbeReturn{
+    /// Drop data in data probes. For push mode this means that the data
+    /// item is not sent downstream. For pull mode, it means that the data
+    /// item is not passed upstream. In both cases, no other probes are
+    /// called for this item and Ok or Flushing is returned to the caller.
+    Drop = GST_PAD_PROBE_DROP,
+    /// Normal probe return value. This leaves the probe in place, and
+    /// defers decisions about dropping or passing data to other probes,
+    /// if any. If there are no other probes, the default behaviour for
+    /// the probe type applies (block for blocking probes, and pass for
+    /// non-blocking probes).
+    Ok = GST_PAD_PROBE_OK,
+    /// Remove this probe.
+    Remove = GST_PAD_PROBE_REMOVE,
+    /// Pass the data item in the block probe and block on the next item.
+    Pass = GST_PAD_PROBE_PASS,
+    /// Data has been handled in the probe and will not be forwarded
+    /// further. For events and buffers this is the same behaviour as Drop,
+    /// the data item is released once the callback returns. For queries it
+    /// will also return true to the caller. The probe can also modify the
+    /// query to answer it.
+    Handled = GST_PAD_PROBE_HANDLED,
+}
+
+/// Identifies a probe installed with `Pad::add_probe`, can be used to
+/// remove it again with `Pad::remove_probe`.
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+pub struct ProbeId(gulong);
+
+impl ProbeId{
+    pub fn as_raw(&self) -> gulong{
+        self.0
+    }
+}
+
 impl Pad{
     pub unsafe fn new(pad: *mut GstPad) -> Option<Pad>{
 		Object::new(pad as *mut GstObject).map(|obj| Pad{ pad: obj })
@@ -38,10 +89,142 @@ impl Pad{
         }
     }
 
+    /// Unlinks the source pad from the sink pad.
+    ///
+    /// Returns true if the pads were unlinked. This function returns false
+    /// if the pads were not linked together.
+    pub fn unlink(&mut self, sink: &mut Pad) -> bool{
+        unsafe{
+            gst_pad_unlink(self.gst_pad_mut(), sink.gst_pad_mut()) != 0
+        }
+    }
+
+    /// Checks if the pad is linked to another pad or not.
     pub fn is_linked(&self) -> bool{
         unsafe{
-            let pad: &mut GstPad = mem::transmute(self.gst_pad());
-            pad.peer != ptr::null_mut()
+            gst_pad_is_linked(self.gst_pad() as *mut GstPad) != 0
+        }
+    }
+
+    /// Gets the direction of the pad. The direction of the pad is decided
+    /// at construction time so this function does not take the LOCK.
+    pub fn direction(&self) -> GstPadDirection{
+        unsafe{
+            gst_pad_get_direction(self.gst_pad() as *mut GstPad)
+        }
+    }
+
+    /// Gets the peer of the pad, None if the pad is not linked.
+    pub fn peer(&self) -> Option<Pad>{
+        unsafe{
+            Pad::new(gst_pad_get_peer(self.gst_pad() as *mut GstPad))
+        }
+    }
+
+    /// Gets the capabilities currently configured on the pad, None when
+    /// the pad has no caps yet, ie. they haven't been negotiated.
+    pub fn current_caps(&self) -> Option<Caps>{
+        unsafe{
+            Caps::new(gst_pad_get_current_caps(self.gst_pad() as *mut GstPad))
+        }
+    }
+
+    /// Gets the capabilities of the allowed media types that can flow
+    /// through the pad and its peer.
+    ///
+    /// The allowed capabilities is calculated as the intersection of the
+    /// results of calling `query_caps` on the pad and its peer.
+    ///
+    /// Returns None when the pad has no peer.
+    pub fn allowed_caps(&self) -> Option<Caps>{
+        unsafe{
+            Caps::new(gst_pad_get_allowed_caps(self.gst_pad() as *mut GstPad))
+        }
+    }
+
+    /// Query if a pad is active
+    pub fn is_active(&self) -> bool{
+        unsafe{
+            gst_pad_is_active(self.gst_pad() as *mut GstPad) != 0
+        }
+    }
+
+    /// Activates or deactivates the given pad. Normally called from within
+    /// core state change functions.
+    ///
+    /// If active, makes sure the pad is active. If it is already active,
+    /// either in push or pull mode, just return. Otherwise dispatches to
+    /// the pad's activate function to perform the actual activation.
+    ///
+    /// If not active, calls the pad's activate mode function with the
+    /// pad's current mode and a false argument.
+    ///
+    /// Returns true if the operation was successful.
+    pub fn set_active(&mut self, active: bool) -> bool{
+        unsafe{
+            gst_pad_set_active(self.gst_pad_mut(), active as gboolean) != 0
+        }
+    }
+
+    /// Checks if the pad is blocked or not. This function returns the last
+    /// requested state of the pad. It is not certain that the pad is
+    /// actually blocking at this point (see `is_blocking`).
+    pub fn is_blocked(&self) -> bool{
+        unsafe{
+            gst_pad_is_blocked(self.gst_pad() as *mut GstPad) != 0
+        }
+    }
+
+    /// Checks if the pad is blocking or not. This is a guaranteed state of
+    /// whether the pad is actually blocking on a buffer or an event.
+    pub fn is_blocking(&self) -> bool{
+        unsafe{
+            gst_pad_is_blocking(self.gst_pad() as *mut GstPad) != 0
+        }
+    }
+
+    /// Gets the parent of the pad as an Element. None if the pad has no
+    /// parent or its parent is not an element, like for the internal pad
+    /// of a ghost pad.
+    pub fn parent_element(&self) -> Option<Element>{
+        unsafe{
+            Element::new_from_gst_element(gst_pad_get_parent_element(self.gst_pad() as *mut GstPad))
+        }
+    }
+
+    /// Gets the template for the pad, None if it was not created from a
+    /// template.
+    pub fn pad_template(&self) -> Option<PadTemplate>{
+        unsafe{
+            PadTemplate::new_from_gst_pad_template(gst_pad_get_pad_template(self.gst_pad() as *mut GstPad))
+        }
+    }
+
+    /// Returns the first sticky event of the given type on the pad, like
+    /// GST_EVENT_CAPS, GST_EVENT_SEGMENT or GST_EVENT_TAG.
+    pub fn sticky_event(&self, ty: GstEventType) -> Option<Event>{
+        self.sticky_event_nth(ty, 0)
+    }
+
+    /// Returns the idx-th sticky event of the given type on the pad. Only
+    /// some event types, like GST_EVENT_TAG, can be stored more than once.
+    pub fn sticky_event_nth(&self, ty: GstEventType, idx: u32) -> Option<Event>{
+        unsafe{
+            Event::new(gst_pad_get_sticky_event(self.gst_pad() as *mut GstPad, ty, idx))
+        }
+    }
+
+    /// Returns an iterator over a snapshot of all the sticky events
+    /// currently stored on the pad, in the order they are pushed.
+    pub fn sticky_events(&self) -> StickyEvents{
+        let mut events: Vec<Event> = Vec::new();
+        unsafe{
+            gst_pad_sticky_events_foreach(self.gst_pad() as *mut GstPad,
+                Some(sticky_events_foreach),
+                &mut events as *mut Vec<Event> as gpointer);
+        }
+        StickyEvents{
+            events: events.into_iter()
         }
     }
 
@@ -52,6 +235,95 @@ impl Pad{
         }
     }
 
+    /// Dispatches a query to a pad. The query should have been allocated
+    /// by the caller. The parse methods of the query can be used to read
+    /// the result once answered.
+    pub fn query(&self, query: &mut Query) -> bool{
+        unsafe{
+            gst_pad_query(self.gst_pad() as *mut GstPad, query.gst_query_mut()) != 0
+        }
+    }
+
+    /// Performs a query on the peer of the pad.
+    ///
+    /// Returns false if the pad is not linked or the peer couldn't answer
+    /// the query.
+    pub fn peer_query(&self, query: &mut Query) -> bool{
+        unsafe{
+            gst_pad_peer_query(self.gst_pad() as *mut GstPad, query.gst_query_mut()) != 0
+        }
+    }
+
+    /// Sends the event to the peer of the pad. This function is mainly
+    /// used by elements to send events to their peer elements.
+    ///
+    /// This function takes ownership of the provided event.
+    pub fn push_event(&mut self, event: Event) -> bool{
+        unsafe{
+            gst_pad_push_event(self.gst_pad_mut(), event.transfer()) != 0
+        }
+    }
+
+    /// Sends the event to the pad. This function can be used by
+    /// applications to send events in the pipeline.
+    ///
+    /// If the pad is a source pad, the event should be an upstream event.
+    /// If the pad is a sink pad, the event should be a downstream event.
+    ///
+    /// This function takes ownership of the provided event.
+    pub fn send_event(&mut self, event: Event) -> bool{
+        unsafe{
+            gst_pad_send_event(self.gst_pad_mut(), event.transfer()) != 0
+        }
+    }
+
+    /// Be notified of different states of pads. The provided callback is
+    /// called for every state that matches mask.
+    ///
+    /// Probes are called in groups: First GST_PAD_PROBE_TYPE_BLOCK probes
+    /// are called, then others, then finally GST_PAD_PROBE_TYPE_IDLE. The
+    /// only exception here are GST_PAD_PROBE_TYPE_IDLE probes that are
+    /// called immediately if the pad is already idle while calling
+    /// add_probe. In each of the groups, probes are called in the order in
+    /// which they were added.
+    ///
+    /// The callback is dropped when the probe is removed, either through
+    /// `remove_probe` or by returning `ProbeReturn::Remove`. It's called
+    /// from the streaming threads, possibly from several at the same time,
+    /// so it has to be Sync.
+    ///
+    /// Returns None if the probe was an idle probe that was called
+    /// immediately and removed itself.
+    ///
+    /// ```ignore
+    /// pad.add_probe(GST_PAD_PROBE_TYPE_BUFFER, |_pad, info| {
+    ///     if let Some(buffer) = info.buffer(){
+    ///         println!("buffer of {} bytes", buffer.size());
+    ///     }
+    ///     ProbeReturn::Ok
+    /// });
+    /// ```
+    pub fn add_probe<F>(&mut self, mask: GstPadProbeType, callback: F) -> Option<ProbeId>
+        where F: Fn(&Pad, &mut ProbeInfo) -> ProbeReturn + Send + Sync + 'static{
+        unsafe{
+            let callback: Box<ProbeCallback> = Box::new(callback);
+            let callback: *mut Box<ProbeCallback> = Box::into_raw(Box::new(callback));
+            let id = gst_pad_add_probe(self.gst_pad_mut(), mask, Some(probe_callback), callback as gpointer, Some(probe_destroy));
+            if id != 0{
+                Some(ProbeId(id))
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Remove the probe with id from the pad, dropping its callback.
+    pub fn remove_probe(&mut self, id: ProbeId){
+        unsafe{
+            gst_pad_remove_probe(self.gst_pad_mut(), id.0);
+        }
+    }
+
     pub unsafe fn gst_pad(&self) -> *const GstPad{
         self.pad.gst_object() as *const GstPad
     }
@@ -73,6 +345,22 @@ impl Reference for Pad{
     }
 }
 
+impl ::FromGValue for Pad{
+    fn from_gvalue(gvalue: &GValue) -> Option<Pad>{
+        unsafe{
+            if value::holds(gvalue, gst_pad_get_type()){
+                let pad = g_value_get_object(gvalue);
+                if pad != ptr::null_mut(){
+                    gst_object_ref(pad);
+                }
+                Pad::new(pad as *mut GstPad)
+            }else{
+                None
+            }
+        }
+    }
+}
+
 impl AsRef<Object> for Pad{
     fn as_ref(&self) -> &Object{
         &self.pad
@@ -103,3 +391,309 @@ impl DerefMut for Pad{
         &mut self.pad
     }
 }
+
+/// Iterator over the sticky events of a pad, see `Pad::sticky_events`
+pub struct StickyEvents{
+    events: vec::IntoIter<Event>,
+}
+
+impl Iterator for StickyEvents{
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event>{
+        self.events.next()
+    }
+}
+
+extern "C" fn sticky_events_foreach(_pad: *mut GstPad, event: *mut *mut GstEvent, data: gpointer) -> gboolean{
+    unsafe{
+        let events = &mut *(data as *mut Vec<Event>);
+        let event = *event;
+        if event != ptr::null_mut(){
+            gst_mini_object_ref(event as *mut GstMiniObject);
+            if let Some(event) = Event::new(event){
+                events.push(event);
+            }
+        }
+        1
+    }
+}
+
+/// Info passed to a pad probe callback.
+///
+/// Depending on the type of the probe, it carries the buffer, buffer list,
+/// event or query that is flowing through the pad. Buffers and events can
+/// be replaced, queries can be answered in place.
+pub struct ProbeInfo<'a>{
+    info: *mut GstPadProbeInfo,
+    query: Option<Query>,
+    phantom: PhantomData<&'a mut GstPadProbeInfo>,
+}
+
+impl<'a> ProbeInfo<'a>{
+    unsafe fn new(info: *mut GstPadProbeInfo) -> ProbeInfo<'a>{
+        let query = if (*info)._type & GST_PAD_PROBE_TYPE_QUERY_BOTH != 0{
+            Query::new(gst_pad_probe_info_get_query(info))
+        }else{
+            None
+        };
+        ProbeInfo{
+            info: info,
+            query: query,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The type of the current probe, a combination of the data type and
+    /// the scheduling and blocking flags
+    pub fn ty(&self) -> GstPadProbeType{
+        unsafe{ (*self.info)._type }
+    }
+
+    /// The id of the probe
+    pub fn id(&self) -> ProbeId{
+        unsafe{ ProbeId((*self.info).id) }
+    }
+
+    /// Offset of pull probe, this field is valid when the type contains
+    /// GST_PAD_PROBE_TYPE_PULL
+    pub fn offset(&self) -> u64{
+        unsafe{ (*self.info).offset }
+    }
+
+    /// Size of pull probe, this field is valid when the type contains
+    /// GST_PAD_PROBE_TYPE_PULL
+    pub fn size(&self) -> u32{
+        unsafe{ (*self.info).size }
+    }
+
+    /// Returns the buffer of this probe if it's a buffer probe.
+    pub fn buffer(&self) -> Option<Buffer>{
+        unsafe{
+            if self.ty() & GST_PAD_PROBE_TYPE_BUFFER != 0{
+                let buffer = gst_pad_probe_info_get_buffer(self.info);
+                if buffer != ptr::null_mut(){
+                    gst_mini_object_ref(buffer as *mut GstMiniObject);
+                }
+                Buffer::new(buffer)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Replaces the buffer of this probe, the new buffer is passed on
+    /// instead of the original one.
+    ///
+    /// Returns the buffer back as an error, without changing the probe
+    /// data, if this is not a buffer probe.
+    pub fn set_buffer(&mut self, buffer: Buffer) -> Result<(), Buffer>{
+        if self.ty() & GST_PAD_PROBE_TYPE_BUFFER == 0{
+            return Err(buffer);
+        }
+        unsafe{
+            self.replace_data(buffer.transfer() as gpointer);
+        }
+        Ok(())
+    }
+
+    /// Returns the buffers of this probe if it's a buffer list probe.
+    pub fn buffer_list(&self) -> Option<Vec<Buffer>>{
+        unsafe{
+            if self.ty() & GST_PAD_PROBE_TYPE_BUFFER_LIST != 0{
+                let list = gst_pad_probe_info_get_buffer_list(self.info);
+                if list != ptr::null_mut(){
+                    Some((0..gst_buffer_list_length(list)).filter_map(|idx|{
+                        let buffer = gst_buffer_list_get(list, idx);
+                        if buffer != ptr::null_mut(){
+                            gst_mini_object_ref(buffer as *mut GstMiniObject);
+                        }
+                        Buffer::new(buffer)
+                    }).collect())
+                }else{
+                    None
+                }
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Returns the event of this probe if it's an event probe.
+    pub fn event(&self) -> Option<Event>{
+        unsafe{
+            if self.ty() & GST_PAD_PROBE_TYPE_EVENT_BOTH != 0{
+                let event = gst_pad_probe_info_get_event(self.info);
+                if event != ptr::null_mut(){
+                    gst_mini_object_ref(event as *mut GstMiniObject);
+                }
+                Event::new(event)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Replaces the event of this probe, the new event is passed on
+    /// instead of the original one.
+    ///
+    /// Returns the event back as an error, without changing the probe
+    /// data, if this is not an event probe.
+    pub fn set_event(&mut self, event: Event) -> Result<(), Event>{
+        if self.ty() & GST_PAD_PROBE_TYPE_EVENT_BOTH == 0{
+            return Err(event);
+        }
+        unsafe{
+            self.replace_data(event.transfer() as gpointer);
+        }
+        Ok(())
+    }
+
+    /// Returns the query of this probe if it's a query probe. The query
+    /// can be answered in place by the callback, returning
+    /// `ProbeReturn::Handled` then returns it to the caller.
+    pub fn query<'b>(&'b mut self) -> Option<ProbeQuery<'b>>{
+        self.query.as_mut().map(|query| ProbeQuery{ query: query })
+    }
+
+    /// The caller has to make sure data is of the type the probe carries
+    unsafe fn replace_data(&mut self, data: gpointer){
+        let old = (*self.info).data;
+        (*self.info).data = data;
+        if old != ptr::null_mut(){
+            gst_mini_object_unref(old as *mut GstMiniObject);
+        }
+    }
+}
+
+/// The query of a query probe. The query is still owned by the caller of
+/// the probe, so it can be read and answered in place but not replaced.
+pub struct ProbeQuery<'a>{
+    query: &'a mut Query,
+}
+
+impl<'a> ProbeQuery<'a>{
+    /// See `Query::set_position`
+    pub fn set_position(&mut self, format: GstFormat, cur: i64){
+        self.query.set_position(format, cur)
+    }
+
+    /// See `Query::set_duration`
+    pub fn set_duration(&mut self, format: GstFormat, duration: i64){
+        self.query.set_duration(format, duration)
+    }
+
+    /// See `Query::set_latency`
+    pub fn set_latency(&mut self, live: bool, min_latency: GstClockTime, max_latency: GstClockTime){
+        self.query.set_latency(live, min_latency, max_latency)
+    }
+
+    /// See `Query::set_seeking`
+    pub fn set_seeking(&mut self, format: GstFormat, seekable: bool, segment_start: i64, segment_end: i64){
+        self.query.set_seeking(format, seekable, segment_start, segment_end)
+    }
+
+    /// See `Query::set_segment`
+    pub fn set_segment(&mut self, rate: f64, format: GstFormat, start: i64, stop: i64){
+        self.query.set_segment(rate, format, start, stop)
+    }
+
+    /// See `Query::set_convert`
+    pub fn set_convert(&mut self, src_format: GstFormat, src_value: i64, dest_format: GstFormat, dest_value: i64){
+        self.query.set_convert(src_format, src_value, dest_format, dest_value)
+    }
+
+    /// See `Query::set_formats`
+    pub fn set_formats(&mut self, formats: &[GstFormat]){
+        self.query.set_formats(formats)
+    }
+
+    /// See `Query::set_buffering_percent`
+    pub fn set_buffering_percent(&mut self, busy: bool, percent: i32){
+        self.query.set_buffering_percent(busy, percent)
+    }
+
+    /// See `Query::set_buffering_stats`
+    pub fn set_buffering_stats(&mut self, mode: GstBufferingMode, avg_in: i32, avg_out: i32, buffering_left: i64){
+        self.query.set_buffering_stats(mode, avg_in, avg_out, buffering_left)
+    }
+
+    /// See `Query::set_buffering_range`
+    pub fn set_buffering_range(&mut self, format: GstFormat, start: i64, stop: i64, estimated_total: i64){
+        self.query.set_buffering_range(format, start, stop, estimated_total)
+    }
+
+    /// See `Query::add_buffering_range`
+    pub fn add_buffering_range(&mut self, start: i64, stop: i64) -> bool{
+        self.query.add_buffering_range(start, stop)
+    }
+
+    /// See `Query::set_uri`
+    pub fn set_uri(&mut self, uri: &str){
+        self.query.set_uri(uri)
+    }
+
+    /// See `Query::set_caps_result`
+    pub fn set_caps_result(&mut self, caps: &Caps){
+        self.query.set_caps_result(caps)
+    }
+
+    /// See `Query::set_accept_caps_result`
+    pub fn set_accept_caps_result(&mut self, result: bool){
+        self.query.set_accept_caps_result(result)
+    }
+}
+
+impl<'a> Deref for ProbeQuery<'a>{
+    type Target = Query;
+    fn deref(&self) -> &Query{
+        self.query
+    }
+}
+
+impl<'a> Drop for ProbeInfo<'a>{
+    fn drop(&mut self){
+        // the query is still owned by the caller of the probe
+        if let Some(query) = self.query.take(){
+            mem::forget(query);
+        }
+    }
+}
+
+trait ProbeCallback: Send + Sync{
+    fn call(&self, pad: &Pad, info: &mut ProbeInfo) -> ProbeReturn;
+}
+
+impl<F: Fn(&Pad, &mut ProbeInfo) -> ProbeReturn + Send + Sync> ProbeCallback for F{
+    fn call(&self, pad: &Pad, info: &mut ProbeInfo) -> ProbeReturn{
+        self(pad, info)
+    }
+}
+
+extern "C" fn probe_callback(pad: *mut GstPad, info: *mut GstPadProbeInfo, data: gpointer) -> GstPadProbeReturn{
+    unsafe{
+        let callback = &*(data as *const Box<ProbeCallback>);
+        gst_object_ref(pad as gpointer);
+        match Pad::new(pad){
+            Some(pad) => {
+                let ret = callback.call(&pad, &mut ProbeInfo::new(info));
+                // handled data is owned by the probe, queries stay with the caller
+                if ret == ProbeReturn::Handled && (*info)._type & GST_PAD_PROBE_TYPE_QUERY_BOTH == 0{
+                    let data = (*info).data;
+                    (*info).data = ptr::null_mut();
+                    if data != ptr::null_mut(){
+                        gst_mini_object_unref(data as *mut GstMiniObject);
+                    }
+                }
+                ret as GstPadProbeReturn
+            }
+            None => GST_PAD_PROBE_OK,
+        }
+    }
+}
+
+extern "C" fn probe_destroy(data: gpointer){
+    unsafe{
+        drop(Box::from_raw(data as *mut Box<ProbeCallback>));
+    }
+}
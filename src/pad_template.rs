@@ -0,0 +1,140 @@
+use ffi::*;
+use caps::Caps;
+use object::Object;
+use reference::Reference;
+use util::*;
+use value;
+
+use std::ops::{Deref, DerefMut};
+
+/// Padtemplates describe the possible media types a pad or an
+/// elementfactory can handle. This allows for both inspection of handled
+/// types before loading the element plugin as well as identifying pads on
+/// elements that are not yet created (request or sometimes pads).
+///
+/// Pad and PadTemplates have Caps attached to it to describe the media
+/// type they are capable of dealing with. The name template is used to
+/// create the name of pads instantiated from the template, like "src_%u"
+/// for request pads.
+pub struct PadTemplate{
+    templ: Object
+}
+
+impl PadTemplate{
+    /// Creates a new pad template with a name according to the given
+    /// template and with the given arguments.
+    pub fn new(name_template: &str, direction: GstPadDirection, presence: GstPadPresence, caps: &Caps) -> Option<PadTemplate>{
+        let cname = CString::new(name_template).unwrap();
+        unsafe{
+            let templ = gst_pad_template_new(cname.as_ptr(), direction, presence, caps.gst_caps() as *mut GstCaps);
+            if templ != ptr::null_mut(){
+                gst_object_ref_sink(templ as gpointer);
+                PadTemplate::new_from_gst_pad_template(templ)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Creates a PadTemplate from an already existing raw pointer to a
+    /// GstPadTemplate. The passed template has to be fully referenced
+    pub unsafe fn new_from_gst_pad_template(templ: *mut GstPadTemplate) -> Option<PadTemplate>{
+        Object::new(templ as *mut GstObject).map(|obj| PadTemplate{ templ: obj })
+    }
+
+    /// The name template of the pad template, like "sink" or "src_%u"
+    pub fn name_template(&self) -> &str{
+        unsafe{
+            from_c_str!((*self.gst_pad_template()).name_template)
+        }
+    }
+
+    /// The direction of the pad described by the pad template
+    pub fn direction(&self) -> GstPadDirection{
+        unsafe{
+            (*self.gst_pad_template()).direction
+        }
+    }
+
+    /// When the pad described by the pad template will become available,
+    /// GST_PAD_ALWAYS, GST_PAD_SOMETIMES or GST_PAD_REQUEST
+    pub fn presence(&self) -> GstPadPresence{
+        unsafe{
+            (*self.gst_pad_template()).presence
+        }
+    }
+
+    /// Gets the capabilities of the pad template.
+    pub fn caps(&self) -> Option<Caps>{
+        unsafe{
+            Caps::new(gst_pad_template_get_caps(self.gst_pad_template() as *mut GstPadTemplate))
+        }
+    }
+
+    pub unsafe fn gst_pad_template(&self) -> *const GstPadTemplate{
+        self.templ.gst_object() as *const GstPadTemplate
+    }
+
+    pub unsafe fn gst_pad_template_mut(&mut self) -> *mut GstPadTemplate{
+        self.templ.gst_object_mut() as *mut GstPadTemplate
+    }
+}
+
+impl ::Transfer<GstPadTemplate> for PadTemplate{
+    unsafe fn transfer(self) -> *mut GstPadTemplate{
+        self.templ.transfer() as *mut GstPadTemplate
+    }
+}
+
+impl Reference for PadTemplate{
+    fn reference(&self) -> PadTemplate{
+        PadTemplate{ templ: self.templ.reference() }
+    }
+}
+
+impl ::FromGValue for PadTemplate{
+    fn from_gvalue(gvalue: &GValue) -> Option<PadTemplate>{
+        unsafe{
+            if value::holds(gvalue, gst_pad_template_get_type()){
+                let templ = g_value_get_object(gvalue);
+                if templ != ptr::null_mut(){
+                    gst_object_ref(templ);
+                }
+                PadTemplate::new_from_gst_pad_template(templ as *mut GstPadTemplate)
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl AsRef<Object> for PadTemplate{
+    fn as_ref(&self) -> &Object{
+        &self.templ
+    }
+}
+
+impl AsMut<Object> for PadTemplate{
+    fn as_mut(&mut self) -> &mut Object{
+        &mut self.templ
+    }
+}
+
+impl From<PadTemplate> for Object{
+    fn from(b: PadTemplate) -> Object{
+        b.templ
+    }
+}
+
+impl Deref for PadTemplate{
+    type Target = Object;
+    fn deref(&self) -> &Object{
+        &self.templ
+    }
+}
+
+impl DerefMut for PadTemplate{
+    fn deref_mut(&mut self) -> &mut Object{
+        &mut self.templ
+    }
+}
@@ -5,6 +5,7 @@ use error::Error;
 use error::Result;
 use util::*;
 use reference::Reference;
+use clock_time::ClockTime;
 
 use std::ops::{Deref, DerefMut};
 
@@ -83,9 +84,9 @@ impl Pipeline{
     }
 
     /// Get the configured delay (see set_delay()).
-    pub fn delay(&self) -> GstClockTime{
+    pub fn delay(&self) -> ClockTime{
         unsafe{
-            gst_pipeline_get_delay(self.gst_pipeline() as *mut GstPipeline)
+            ClockTime::from_gst_clock_time(gst_pipeline_get_delay(self.gst_pipeline() as *mut GstPipeline))
         }
     }
 
@@ -93,12 +94,12 @@ impl Pipeline{
     /// PAUSED to PLAYING state change. delay will be added to the base
     /// time of the elements so that they wait an additional delay amount
     /// of time before starting to process buffers and cannot be
-    /// GST_CLOCK_TIME_NONE.
+    /// ClockTime::NONE.
 	///
 	/// This option is used for tuning purposes and should normally not be used.
-    pub fn set_delay(&mut self, delay: GstClockTime){
+    pub fn set_delay(&mut self, delay: ClockTime){
         unsafe{
-            gst_pipeline_set_delay(self.gst_pipeline_mut(), delay);
+            gst_pipeline_set_delay(self.gst_pipeline_mut(), delay.to_gst_clock_time());
         }
     }
 
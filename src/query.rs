@@ -0,0 +1,697 @@
+use ffi::*;
+use util::*;
+use std::os::raw::c_char;
+use std::ops::{Deref, DerefMut};
+
+use caps::Caps;
+use structure::Structure;
+use reference::Reference;
+use miniobject::MiniObject;
+use ::Transfer;
+
+unsafe impl Send for Query {}
+
+/// Queries can be performed on pads with `Pad::query` and `Pad::peer_query`
+/// and on elements with `Element::query`.
+///
+/// Queries are created with one of the `new_*` constructors, sent to an
+/// element or pad and once answered parsed with the corresponding
+/// `parse_*` method. Each parse method returns None if the query is not
+/// of the expected type.
+#[derive(Clone)]
+pub struct Query{
+    query: MiniObject
+}
+
+/// Parsed result of a seeking query
+#[derive(Debug,Clone,Copy)]
+pub struct SeekingInfo{
+    pub format: GstFormat,
+    pub seekable: bool,
+    pub segment_start: i64,
+    pub segment_end: i64,
+}
+
+/// Parsed result of a segment query
+#[derive(Debug,Clone,Copy)]
+pub struct SegmentInfo{
+    pub rate: f64,
+    pub format: GstFormat,
+    pub start: i64,
+    pub stop: i64,
+}
+
+/// Parsed result of a convert query
+#[derive(Debug,Clone,Copy)]
+pub struct ConvertInfo{
+    pub src_format: GstFormat,
+    pub src_value: i64,
+    pub dest_format: GstFormat,
+    pub dest_value: i64,
+}
+
+/// Parsed buffering stats of a buffering query
+#[derive(Debug,Clone,Copy)]
+pub struct BufferingStats{
+    pub mode: GstBufferingMode,
+    pub avg_in: i32,
+    pub avg_out: i32,
+    pub buffering_left: i64,
+}
+
+/// Parsed buffering range of a buffering query
+#[derive(Debug,Clone,Copy)]
+pub struct BufferingRange{
+    pub format: GstFormat,
+    pub start: i64,
+    pub stop: i64,
+    pub estimated_total: i64,
+}
+
+impl Query{
+    pub unsafe fn new(query: *mut GstQuery) -> Option<Query>{
+        MiniObject::new_from_gst_miniobject(query as *mut GstMiniObject)
+            .map(|miniobject| Query{ query: miniobject })
+    }
+
+    /// Constructs a new query stream position query object. A position
+    /// query is used to query the current position of playback in the
+    /// streams, in some format.
+    pub fn new_position(format: GstFormat) -> Query{
+        unsafe{
+            Query::new(gst_query_new_position(format)).unwrap()
+        }
+    }
+
+    /// Constructs a new stream duration query object to query in the
+    /// given format. A duration query will give the total length of
+    /// the stream.
+    pub fn new_duration(format: GstFormat) -> Query{
+        unsafe{
+            Query::new(gst_query_new_duration(format)).unwrap()
+        }
+    }
+
+    /// Constructs a new latency query object. A latency query is usually
+    /// performed by sinks to compensate for additional latency introduced
+    /// by elements in the pipeline.
+    pub fn new_latency() -> Query{
+        unsafe{
+            Query::new(gst_query_new_latency()).unwrap()
+        }
+    }
+
+    /// Constructs a new query object for querying seeking properties of
+    /// the stream.
+    pub fn new_seeking(format: GstFormat) -> Query{
+        unsafe{
+            Query::new(gst_query_new_seeking(format)).unwrap()
+        }
+    }
+
+    /// Constructs a new segment query object. A segment query is used to
+    /// discover information about the currently configured segment for
+    /// playback.
+    pub fn new_segment(format: GstFormat) -> Query{
+        unsafe{
+            Query::new(gst_query_new_segment(format)).unwrap()
+        }
+    }
+
+    /// Constructs a new convert query object. A convert query is used to
+    /// ask for a conversion between one format and another.
+    pub fn new_convert(src_format: GstFormat, value: i64, dest_format: GstFormat) -> Query{
+        unsafe{
+            Query::new(gst_query_new_convert(src_format, value, dest_format)).unwrap()
+        }
+    }
+
+    /// Constructs a new query object for querying formats of the stream.
+    pub fn new_formats() -> Query{
+        unsafe{
+            Query::new(gst_query_new_formats()).unwrap()
+        }
+    }
+
+    /// Constructs a new query object for querying the buffering status of
+    /// a stream.
+    pub fn new_buffering(format: GstFormat) -> Query{
+        unsafe{
+            Query::new(gst_query_new_buffering(format)).unwrap()
+        }
+    }
+
+    /// Constructs a new query URI query object. An URI query is used to
+    /// query the current URI that is used by the source or sink.
+    pub fn new_uri() -> Query{
+        unsafe{
+            Query::new(gst_query_new_uri()).unwrap()
+        }
+    }
+
+    /// Constructs a new query object for querying the caps.
+    ///
+    /// The CAPS query should return the allowable caps for a pad in the
+    /// context of the element's state, its link to other elements, and the
+    /// devices or files it has opened. These caps must be a subset of the
+    /// pad template caps.
+    ///
+    /// The filter is used to restrict the result caps, only the caps
+    /// matching filter should be returned from the CAPS query.
+    pub fn new_caps(filter: Option<&Caps>) -> Query{
+        unsafe{
+            let filter = filter.map(|caps| caps.gst_caps() as *mut GstCaps).unwrap_or(ptr::null_mut());
+            Query::new(gst_query_new_caps(filter)).unwrap()
+        }
+    }
+
+    /// Constructs a new query object for querying if caps are accepted.
+    pub fn new_accept_caps(caps: &Caps) -> Query{
+        unsafe{
+            Query::new(gst_query_new_accept_caps(caps.gst_caps() as *mut GstCaps)).unwrap()
+        }
+    }
+
+    /// Constructs a new query object for querying the scheduling
+    /// properties.
+    pub fn new_scheduling() -> Query{
+        unsafe{
+            Query::new(gst_query_new_scheduling()).unwrap()
+        }
+    }
+
+    /// Constructs a new query object for querying the drain state.
+    pub fn new_drain() -> Query{
+        unsafe{
+            Query::new(gst_query_new_drain()).unwrap()
+        }
+    }
+
+    /// Constructs a new custom query object. Use it for any query not
+    /// handled by the other query constructors.
+    pub fn new_custom(ty: GstQueryType, structure: Structure) -> Option<Query>{
+        unsafe{
+            Query::new(gst_query_new_custom(ty, structure.transfer()))
+        }
+    }
+
+    pub fn ty(&self) -> GstQueryType{
+        unsafe{
+            (*self.gst_query())._type
+        }
+    }
+
+    pub fn type_name(&self) -> &str{
+        unsafe{
+            from_c_str!(gst_query_type_get_name(self.ty()))
+        }
+    }
+
+    fn type_flags(&self) -> GstQueryTypeFlags{
+        unsafe{
+            gst_query_type_get_flags(self.ty())
+        }
+    }
+
+    /// Returns true if the query can travel upstream
+    pub fn is_upstream(&self) -> bool{
+        self.type_flags() & GST_QUERY_TYPE_UPSTREAM != 0
+    }
+
+    /// Returns true if the query can travel downstream
+    pub fn is_downstream(&self) -> bool{
+        self.type_flags() & GST_QUERY_TYPE_DOWNSTREAM != 0
+    }
+
+    /// Returns true if the query is serialized with the data flow
+    pub fn is_serialized(&self) -> bool{
+        self.type_flags() & GST_QUERY_TYPE_SERIALIZED != 0
+    }
+
+    pub fn is_writable(&self) -> bool{
+        unsafe{
+            gst_mini_object_is_writable(self.query.gst_miniobject()) != 0
+        }
+    }
+
+    /// Get the structure of a query.
+    pub fn structure(&self) -> Option<Structure>{
+        unsafe{
+            let structure = gst_query_get_structure(self.gst_query() as *mut GstQuery);
+            if structure != ptr::null(){
+                Structure::new_from_gst_structure(gst_structure_copy(structure))
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Parse a position query, returns the format and the position in
+    /// that format.
+    pub fn parse_position(&self) -> Option<(GstFormat, i64)>{
+        if self.ty() != GST_QUERY_POSITION{
+            return None;
+        }
+        unsafe{
+            let mut format = GST_FORMAT_UNDEFINED;
+            let mut cur = 0;
+            gst_query_parse_position(self.gst_query() as *mut GstQuery, &mut format, &mut cur);
+            Some((format, cur))
+        }
+    }
+
+    /// Answer a position query by setting the requested value in the
+    /// given format.
+    pub fn set_position(&mut self, format: GstFormat, cur: i64){
+        unsafe{
+            gst_query_set_position(self.gst_query_mut(), format, cur)
+        }
+    }
+
+    /// Parse a duration query, returns the format and the duration in
+    /// that format.
+    pub fn parse_duration(&self) -> Option<(GstFormat, i64)>{
+        if self.ty() != GST_QUERY_DURATION{
+            return None;
+        }
+        unsafe{
+            let mut format = GST_FORMAT_UNDEFINED;
+            let mut duration = 0;
+            gst_query_parse_duration(self.gst_query() as *mut GstQuery, &mut format, &mut duration);
+            Some((format, duration))
+        }
+    }
+
+    /// Answer a duration query by setting the requested value in the
+    /// given format.
+    pub fn set_duration(&mut self, format: GstFormat, duration: i64){
+        unsafe{
+            gst_query_set_duration(self.gst_query_mut(), format, duration)
+        }
+    }
+
+    /// Parse a latency query, returns if the pipeline is live and the
+    /// minimum and maximum latency.
+    pub fn parse_latency(&self) -> Option<(bool, GstClockTime, GstClockTime)>{
+        if self.ty() != GST_QUERY_LATENCY{
+            return None;
+        }
+        unsafe{
+            let mut live = 0;
+            let mut min = 0;
+            let mut max = 0;
+            gst_query_parse_latency(self.gst_query() as *mut GstQuery, &mut live, &mut min, &mut max);
+            Some((live != 0, min, max))
+        }
+    }
+
+    /// Answer a latency query by setting the requested values.
+    pub fn set_latency(&mut self, live: bool, min_latency: GstClockTime, max_latency: GstClockTime){
+        unsafe{
+            gst_query_set_latency(self.gst_query_mut(), live as gboolean, min_latency, max_latency)
+        }
+    }
+
+    /// Parse a seeking query, returns if the stream is seekable in the
+    /// given format and the seekable range.
+    pub fn parse_seeking(&self) -> Option<SeekingInfo>{
+        if self.ty() != GST_QUERY_SEEKING{
+            return None;
+        }
+        unsafe{
+            let mut format = GST_FORMAT_UNDEFINED;
+            let mut seekable = 0;
+            let mut segment_start = 0;
+            let mut segment_end = 0;
+            gst_query_parse_seeking(self.gst_query() as *mut GstQuery, &mut format, &mut seekable, &mut segment_start, &mut segment_end);
+            Some(SeekingInfo{
+                format: format,
+                seekable: seekable != 0,
+                segment_start: segment_start,
+                segment_end: segment_end,
+            })
+        }
+    }
+
+    /// Answer a seeking query.
+    pub fn set_seeking(&mut self, format: GstFormat, seekable: bool, segment_start: i64, segment_end: i64){
+        unsafe{
+            gst_query_set_seeking(self.gst_query_mut(), format, seekable as gboolean, segment_start, segment_end)
+        }
+    }
+
+    /// Parse a segment query.
+    pub fn parse_segment(&self) -> Option<SegmentInfo>{
+        if self.ty() != GST_QUERY_SEGMENT{
+            return None;
+        }
+        unsafe{
+            let mut rate = 0.0;
+            let mut format = GST_FORMAT_UNDEFINED;
+            let mut start = 0;
+            let mut stop = 0;
+            gst_query_parse_segment(self.gst_query() as *mut GstQuery, &mut rate, &mut format, &mut start, &mut stop);
+            Some(SegmentInfo{
+                rate: rate,
+                format: format,
+                start: start,
+                stop: stop,
+            })
+        }
+    }
+
+    /// Answer a segment query.
+    pub fn set_segment(&mut self, rate: f64, format: GstFormat, start: i64, stop: i64){
+        unsafe{
+            gst_query_set_segment(self.gst_query_mut(), rate, format, start, stop)
+        }
+    }
+
+    /// Parse a convert query.
+    pub fn parse_convert(&self) -> Option<ConvertInfo>{
+        if self.ty() != GST_QUERY_CONVERT{
+            return None;
+        }
+        unsafe{
+            let mut src_format = GST_FORMAT_UNDEFINED;
+            let mut src_value = 0;
+            let mut dest_format = GST_FORMAT_UNDEFINED;
+            let mut dest_value = 0;
+            gst_query_parse_convert(self.gst_query() as *mut GstQuery, &mut src_format, &mut src_value, &mut dest_format, &mut dest_value);
+            Some(ConvertInfo{
+                src_format: src_format,
+                src_value: src_value,
+                dest_format: dest_format,
+                dest_value: dest_value,
+            })
+        }
+    }
+
+    /// Answer a convert query.
+    pub fn set_convert(&mut self, src_format: GstFormat, src_value: i64, dest_format: GstFormat, dest_value: i64){
+        unsafe{
+            gst_query_set_convert(self.gst_query_mut(), src_format, src_value, dest_format, dest_value)
+        }
+    }
+
+    /// Parse a formats query, returns the list of supported formats.
+    pub fn parse_formats(&self) -> Option<Vec<GstFormat>>{
+        if self.ty() != GST_QUERY_FORMATS{
+            return None;
+        }
+        unsafe{
+            let mut n_formats = 0;
+            gst_query_parse_n_formats(self.gst_query() as *mut GstQuery, &mut n_formats);
+            Some((0..n_formats).map(|i| {
+                let mut format = GST_FORMAT_UNDEFINED;
+                gst_query_parse_nth_format(self.gst_query() as *mut GstQuery, i, &mut format);
+                format
+            }).collect())
+        }
+    }
+
+    /// Answer a formats query with the list of supported formats.
+    pub fn set_formats(&mut self, formats: &[GstFormat]){
+        unsafe{
+            gst_query_set_formatsv(self.gst_query_mut(), formats.len() as gint, formats.as_ptr())
+        }
+    }
+
+    /// Parse a buffering query, returns if the element is busy buffering
+    /// and the buffering percent.
+    pub fn parse_buffering_percent(&self) -> Option<(bool, i32)>{
+        if self.ty() != GST_QUERY_BUFFERING{
+            return None;
+        }
+        unsafe{
+            let mut busy = 0;
+            let mut percent = 0;
+            gst_query_parse_buffering_percent(self.gst_query() as *mut GstQuery, &mut busy, &mut percent);
+            Some((busy != 0, percent))
+        }
+    }
+
+    /// Answer a buffering query with the buffering percent.
+    pub fn set_buffering_percent(&mut self, busy: bool, percent: i32){
+        unsafe{
+            gst_query_set_buffering_percent(self.gst_query_mut(), busy as gboolean, percent)
+        }
+    }
+
+    /// Parse the buffering stats of a buffering query.
+    pub fn parse_buffering_stats(&self) -> Option<BufferingStats>{
+        if self.ty() != GST_QUERY_BUFFERING{
+            return None;
+        }
+        unsafe{
+            let mut mode = GST_BUFFERING_STREAM;
+            let mut avg_in = 0;
+            let mut avg_out = 0;
+            let mut buffering_left = 0;
+            gst_query_parse_buffering_stats(self.gst_query() as *mut GstQuery, &mut mode, &mut avg_in, &mut avg_out, &mut buffering_left);
+            Some(BufferingStats{
+                mode: mode,
+                avg_in: avg_in,
+                avg_out: avg_out,
+                buffering_left: buffering_left,
+            })
+        }
+    }
+
+    /// Answer a buffering query with the buffering stats.
+    pub fn set_buffering_stats(&mut self, mode: GstBufferingMode, avg_in: i32, avg_out: i32, buffering_left: i64){
+        unsafe{
+            gst_query_set_buffering_stats(self.gst_query_mut(), mode, avg_in, avg_out, buffering_left)
+        }
+    }
+
+    /// Parse the available range of a buffering query.
+    pub fn parse_buffering_range(&self) -> Option<BufferingRange>{
+        if self.ty() != GST_QUERY_BUFFERING{
+            return None;
+        }
+        unsafe{
+            let mut format = GST_FORMAT_UNDEFINED;
+            let mut start = 0;
+            let mut stop = 0;
+            let mut estimated_total = 0;
+            gst_query_parse_buffering_range(self.gst_query() as *mut GstQuery, &mut format, &mut start, &mut stop, &mut estimated_total);
+            Some(BufferingRange{
+                format: format,
+                start: start,
+                stop: stop,
+                estimated_total: estimated_total,
+            })
+        }
+    }
+
+    /// Answer a buffering query with the available range.
+    pub fn set_buffering_range(&mut self, format: GstFormat, start: i64, stop: i64, estimated_total: i64){
+        unsafe{
+            gst_query_set_buffering_range(self.gst_query_mut(), format, start, stop, estimated_total)
+        }
+    }
+
+    /// Returns the list of (start, stop) ranges that are already buffered.
+    pub fn buffering_ranges(&self) -> Option<Vec<(i64, i64)>>{
+        if self.ty() != GST_QUERY_BUFFERING{
+            return None;
+        }
+        unsafe{
+            let query = self.gst_query() as *mut GstQuery;
+            let n_ranges = gst_query_get_n_buffering_ranges(query);
+            Some((0..n_ranges).filter_map(|i| {
+                let mut start = 0;
+                let mut stop = 0;
+                if gst_query_parse_nth_buffering_range(query, i, &mut start, &mut stop) != 0{
+                    Some((start, stop))
+                }else{
+                    None
+                }
+            }).collect())
+        }
+    }
+
+    /// Add a range to the list of buffered ranges. Returns false if the
+    /// range is not valid or overlaps the last added range.
+    pub fn add_buffering_range(&mut self, start: i64, stop: i64) -> bool{
+        unsafe{
+            gst_query_add_buffering_range(self.gst_query_mut(), start, stop) != 0
+        }
+    }
+
+    /// Parse an URI query, returns the URI if one was set.
+    pub fn parse_uri(&self) -> Option<String>{
+        if self.ty() != GST_QUERY_URI{
+            return None;
+        }
+        unsafe{
+            let mut uri: *mut c_char = ptr::null_mut();
+            gst_query_parse_uri(self.gst_query() as *mut GstQuery, &mut uri);
+            take_string(uri)
+        }
+    }
+
+    /// Answer an URI query.
+    pub fn set_uri(&mut self, uri: &str){
+        let curi = CString::new(uri).unwrap();
+        unsafe{
+            gst_query_set_uri(self.gst_query_mut(), curi.as_ptr())
+        }
+    }
+
+    /// Parse the redirection URI of an URI query, if any.
+    pub fn parse_uri_redirection(&self) -> Option<String>{
+        if self.ty() != GST_QUERY_URI{
+            return None;
+        }
+        unsafe{
+            let mut uri: *mut c_char = ptr::null_mut();
+            gst_query_parse_uri_redirection(self.gst_query() as *mut GstQuery, &mut uri);
+            take_string(uri)
+        }
+    }
+
+    /// Parse if the redirection of an URI query is permanent.
+    pub fn parse_uri_redirection_permanent(&self) -> Option<bool>{
+        if self.ty() != GST_QUERY_URI{
+            return None;
+        }
+        unsafe{
+            let mut permanent = 0;
+            gst_query_parse_uri_redirection_permanent(self.gst_query() as *mut GstQuery, &mut permanent);
+            Some(permanent != 0)
+        }
+    }
+
+    /// Get the filter from a caps query.
+    pub fn parse_caps(&self) -> Option<Caps>{
+        if self.ty() != GST_QUERY_CAPS{
+            return None;
+        }
+        unsafe{
+            let mut filter: *mut GstCaps = ptr::null_mut();
+            gst_query_parse_caps(self.gst_query() as *mut GstQuery, &mut filter);
+            ref_caps(filter)
+        }
+    }
+
+    /// Get the caps result from a caps query.
+    pub fn caps_result(&self) -> Option<Caps>{
+        if self.ty() != GST_QUERY_CAPS{
+            return None;
+        }
+        unsafe{
+            let mut caps: *mut GstCaps = ptr::null_mut();
+            gst_query_parse_caps_result(self.gst_query() as *mut GstQuery, &mut caps);
+            ref_caps(caps)
+        }
+    }
+
+    /// Answer a caps query.
+    pub fn set_caps_result(&mut self, caps: &Caps){
+        unsafe{
+            gst_query_set_caps_result(self.gst_query_mut(), caps.gst_caps() as *mut GstCaps)
+        }
+    }
+
+    /// Get the caps from an accept-caps query.
+    pub fn parse_accept_caps(&self) -> Option<Caps>{
+        if self.ty() != GST_QUERY_ACCEPT_CAPS{
+            return None;
+        }
+        unsafe{
+            let mut caps: *mut GstCaps = ptr::null_mut();
+            gst_query_parse_accept_caps(self.gst_query() as *mut GstQuery, &mut caps);
+            ref_caps(caps)
+        }
+    }
+
+    /// Get the result of an accept-caps query.
+    pub fn accept_caps_result(&self) -> Option<bool>{
+        if self.ty() != GST_QUERY_ACCEPT_CAPS{
+            return None;
+        }
+        unsafe{
+            let mut result = 0;
+            gst_query_parse_accept_caps_result(self.gst_query() as *mut GstQuery, &mut result);
+            Some(result != 0)
+        }
+    }
+
+    /// Answer an accept-caps query.
+    pub fn set_accept_caps_result(&mut self, result: bool){
+        unsafe{
+            gst_query_set_accept_caps_result(self.gst_query_mut(), result as gboolean)
+        }
+    }
+
+    pub unsafe fn gst_query(&self) -> *const GstQuery{
+        self.query.gst_miniobject() as *const GstQuery
+    }
+
+    pub unsafe fn gst_query_mut(&mut self) -> *mut GstQuery{
+        self.query.gst_miniobject_mut() as *mut GstQuery
+    }
+}
+
+unsafe fn take_string(string: *mut c_char) -> Option<String>{
+    if string != ptr::null_mut(){
+        let ret = from_c_str!(string).to_string();
+        g_free(string as gpointer);
+        Some(ret)
+    }else{
+        None
+    }
+}
+
+unsafe fn ref_caps(caps: *mut GstCaps) -> Option<Caps>{
+    if caps != ptr::null_mut(){
+        Caps::new(gst_mini_object_ref(caps as *mut GstMiniObject) as *mut GstCaps)
+    }else{
+        None
+    }
+}
+
+impl ::Transfer<GstQuery> for Query{
+    unsafe fn transfer(self) ->  *mut GstQuery{
+        self.query.transfer() as *mut GstQuery
+    }
+}
+
+impl Reference for Query{
+    fn reference(&self) -> Query{
+        Query{ query: self.query.reference() }
+    }
+}
+
+impl AsRef<MiniObject> for Query{
+    fn as_ref(&self) -> &MiniObject{
+        &self.query
+    }
+}
+
+impl AsMut<MiniObject> for Query{
+    fn as_mut(&mut self) -> &mut MiniObject{
+        &mut self.query
+    }
+}
+
+impl From<Query> for MiniObject{
+    fn from(q: Query) -> MiniObject{
+        q.query
+    }
+}
+
+impl Deref for Query{
+    type Target = MiniObject;
+    fn deref(&self) -> &MiniObject{
+        &self.query
+    }
+}
+
+impl DerefMut for Query{
+    fn deref_mut(&mut self) -> &mut MiniObject{
+        &mut self.query
+    }
+}
@@ -1,11 +1,13 @@
 use ffi::*;
 use caps::Caps;
 use buffer::Buffer;
+use segment::Segment;
 use videoframe::VideoFrame;
 use std::mem;
 use std::ptr;
 use reference::Reference;
 use miniobject::MiniObject;
+use value;
 
 unsafe impl Send for Sample {}
 
@@ -45,9 +47,9 @@ impl Sample{
 	}
 
     /// Get the segment associated with sample
-    pub fn segment(&self) -> GstSegment{
+    pub fn segment(&self) -> Segment{
         unsafe{
-            (*gst_sample_get_segment(mem::transmute(self.gst_sample())))
+            Segment::new_from_gst_segment(&*gst_sample_get_segment(mem::transmute(self.gst_sample())))
         }
     }
 
@@ -84,6 +86,31 @@ impl ::Transfer<GstSample> for Sample{
     }
 }
 
+impl ::FromGValue for Sample{
+    fn from_gvalue(gvalue: &GValue) -> Option<Sample>{
+        unsafe{
+            if value::holds(gvalue, gst_sample_get_type()){
+                let sample = g_value_get_boxed(gvalue) as *mut GstMiniObject;
+                if sample != ptr::null_mut(){
+                    Sample::new(gst_mini_object_ref(sample) as *mut GstSample)
+                }else{
+                    None
+                }
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ::ToGValue for Sample{
+    fn to_gvalue(&self) -> GValue{
+        let mut gvalue = value::new_gvalue(unsafe{ gst_sample_get_type() });
+        unsafe{ g_value_set_boxed(&mut gvalue, self.gst_sample() as gconstpointer) };
+        gvalue
+    }
+}
+
 impl Reference for Sample{
     fn reference(&self) -> Sample{
         Sample{
@@ -0,0 +1,254 @@
+use ffi::*;
+use event::Event;
+use format::Format;
+
+use std::ops::{BitOr, BitOrAssign};
+
+/// Flags to be used with `Seek` and `Element::seek_with`. They can be
+/// combined with `|`.
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+pub struct SeekFlags(GstSeekFlags);
+
+impl SeekFlags{
+    /// No flag
+    pub const NONE: SeekFlags = SeekFlags(GST_SEEK_FLAG_NONE);
+    /// Flush pipeline
+    pub const FLUSH: SeekFlags = SeekFlags(GST_SEEK_FLAG_FLUSH);
+    /// Accurate position is requested, this might be considerably slower
+    /// for some formats.
+    pub const ACCURATE: SeekFlags = SeekFlags(GST_SEEK_FLAG_ACCURATE);
+    /// Seek to the nearest keyframe. This might be faster but less
+    /// accurate.
+    pub const KEY_UNIT: SeekFlags = SeekFlags(GST_SEEK_FLAG_KEY_UNIT);
+    /// Perform a segment seek.
+    pub const SEGMENT: SeekFlags = SeekFlags(GST_SEEK_FLAG_SEGMENT);
+    /// When doing fast forward or fast reverse playback, allow elements to
+    /// skip frames instead of generating all frames.
+    pub const TRICKMODE: SeekFlags = SeekFlags(GST_SEEK_FLAG_SKIP);
+    /// Go to a location before the requested position, if KEY_UNIT this
+    /// means the keyframe at or before the requested position the one at
+    /// or before the seek target.
+    pub const SNAP_BEFORE: SeekFlags = SeekFlags(GST_SEEK_FLAG_SNAP_BEFORE);
+    /// Go to a location after the requested position, if KEY_UNIT this
+    /// means the keyframe at of after the requested position.
+    pub const SNAP_AFTER: SeekFlags = SeekFlags(GST_SEEK_FLAG_SNAP_AFTER);
+    /// Go to a position near the requested position, if KEY_UNIT this
+    /// means the keyframe closest to the requested position, if both
+    /// keyframes are at an equal distance, behaves like SNAP_BEFORE.
+    pub const SNAP_NEAREST: SeekFlags = SeekFlags(GST_SEEK_FLAG_SNAP_NEAREST);
+
+    /// Creates flags from the raw GstSeekFlags
+    pub fn from_bits(bits: GstSeekFlags) -> SeekFlags{
+        SeekFlags(bits)
+    }
+
+    /// The raw GstSeekFlags
+    pub fn bits(&self) -> GstSeekFlags{
+        self.0
+    }
+
+    /// Returns true if all the flags in other are set
+    pub fn contains(&self, other: SeekFlags) -> bool{
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for SeekFlags{
+    type Output = SeekFlags;
+    fn bitor(self, other: SeekFlags) -> SeekFlags{
+        SeekFlags(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for SeekFlags{
+    fn bitor_assign(&mut self, other: SeekFlags){
+        self.0 |= other.0;
+    }
+}
+
+/// The different types of seek events. When constructing a seek event
+/// with `Seek` a format, a seek method and optional flags are to be
+/// provided.
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+pub enum SeekType{
+    /// No change in position is required
+    None,
+    /// Absolute position is requested
+    Set,
+    /// Relative position to duration is requested
+    End,
+}
+
+impl From<GstSeekType> for SeekType{
+    fn from(ty: GstSeekType) -> SeekType{
+        match ty{
+            GST_SEEK_TYPE_SET => SeekType::Set,
+            GST_SEEK_TYPE_END => SeekType::End,
+            _ => SeekType::None,
+        }
+    }
+}
+
+impl From<SeekType> for GstSeekType{
+    fn from(ty: SeekType) -> GstSeekType{
+        match ty{
+            SeekType::None => GST_SEEK_TYPE_NONE,
+            SeekType::Set => GST_SEEK_TYPE_SET,
+            SeekType::End => GST_SEEK_TYPE_END,
+        }
+    }
+}
+
+/// Builder for seeks, to be performed with `Element::seek_with` or sent
+/// as an event with `to_event`.
+///
+/// A seek configures playback of the pipeline between start to stop at
+/// the speed given in rate, also called a playback segment. The start and
+/// stop values are expressed in format, nanoseconds by default. A rate
+/// of 1.0 means normal playback rate, 2.0 means double speed. Negatives
+/// values means backwards playback. A value of 0.0 for the rate is not
+/// allowed and should be accomplished instead by PAUSING the pipeline.
+///
+/// Start and stop that are not set are left unchanged. They take any value
+/// convertible to i64, like a ClockTime for Format::Time, where a NONE
+/// stop means the end of the stream.
+///
+/// ```ignore
+/// // frame accurate seek to 10s
+/// pipeline.seek_with(&Seek::new().flush().accurate().start(ClockTime::from_seconds(10)));
+///
+/// // loop between 5s and 8s using segment seeks
+/// pipeline.seek_with(&Seek::new().flush().segment().start(5 * ClockTime::SECOND).stop(8 * ClockTime::SECOND));
+///
+/// // 4x fast forward from the current position
+/// pipeline.seek_with(&Seek::new().rate(4.0).flush().trickmode().start(pipeline.position()));
+/// ```
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub struct Seek{
+    rate: f64,
+    format: Format,
+    flags: SeekFlags,
+    start_type: SeekType,
+    start: i64,
+    stop_type: SeekType,
+    stop: i64,
+}
+
+impl Seek{
+    /// Creates a new seek with rate 1.0 in Format::Time without flags,
+    /// leaving start and stop unchanged.
+    pub fn new() -> Seek{
+        Seek{
+            rate: 1.0,
+            format: Format::Time,
+            flags: SeekFlags::NONE,
+            start_type: SeekType::None,
+            start: -1,
+            stop_type: SeekType::None,
+            stop: -1,
+        }
+    }
+
+    /// The new playback rate
+    pub fn rate(mut self, rate: f64) -> Seek{
+        self.rate = rate;
+        self
+    }
+
+    /// The format of the seek values
+    pub fn format(mut self, format: Format) -> Seek{
+        self.format = format;
+        self
+    }
+
+    /// Adds flags to the seek
+    pub fn flags(mut self, flags: SeekFlags) -> Seek{
+        self.flags |= flags;
+        self
+    }
+
+    /// Flush the pipeline, without it the seek only happens once the
+    /// currently queued data has been played.
+    pub fn flush(self) -> Seek{
+        self.flags(SeekFlags::FLUSH)
+    }
+
+    /// Seek to the exact position, this might be considerably slower for
+    /// some formats.
+    pub fn accurate(self) -> Seek{
+        self.flags(SeekFlags::ACCURATE)
+    }
+
+    /// Seek to the nearest keyframe, faster but less accurate.
+    pub fn key_unit(self) -> Seek{
+        self.flags(SeekFlags::KEY_UNIT)
+    }
+
+    /// Go to the keyframe at or before the requested position.
+    pub fn snap_before(self) -> Seek{
+        self.flags(SeekFlags::SNAP_BEFORE)
+    }
+
+    /// Go to the keyframe at or after the requested position.
+    pub fn snap_after(self) -> Seek{
+        self.flags(SeekFlags::SNAP_AFTER)
+    }
+
+    /// Go to the keyframe nearest to the requested position.
+    pub fn snap_nearest(self) -> Seek{
+        self.flags(SeekFlags::SNAP_NEAREST)
+    }
+
+    /// Perform a segment seek, a SEGMENT_DONE message is posted instead
+    /// of EOS when the stop position is reached. This allows seamless
+    /// looping by seeking again when the message arrives.
+    pub fn segment(self) -> Seek{
+        self.flags(SeekFlags::SEGMENT)
+    }
+
+    /// Allow elements to skip frames when doing fast forward or fast
+    /// reverse playback.
+    pub fn trickmode(self) -> Seek{
+        self.flags(SeekFlags::TRICKMODE)
+    }
+
+    /// Absolute start position, in format
+    pub fn start<P: Into<i64>>(mut self, start: P) -> Seek{
+        self.start_type = SeekType::Set;
+        self.start = start.into();
+        self
+    }
+
+    /// Absolute stop position, in format
+    pub fn stop<P: Into<i64>>(mut self, stop: P) -> Seek{
+        self.stop_type = SeekType::Set;
+        self.stop = stop.into();
+        self
+    }
+
+    /// Sets how the start position is interpreted, for example
+    /// SeekType::End to seek relative to the end of the stream.
+    pub fn start_type(mut self, start_type: SeekType) -> Seek{
+        self.start_type = start_type;
+        self
+    }
+
+    /// Sets how the stop position is interpreted, for example
+    /// SeekType::End to stop relative to the end of the stream.
+    pub fn stop_type(mut self, stop_type: SeekType) -> Seek{
+        self.stop_type = stop_type;
+        self
+    }
+
+    /// Creates the seek event for this seek, None if the parameters are
+    /// not valid, for example a rate of 0.
+    pub fn to_event(&self) -> Option<Event>{
+        Event::new_seek(self.rate,
+            self.format.into(),
+            self.flags.bits(),
+            self.start_type.into(),
+            self.start,
+            self.stop_type.into(),
+            self.stop)
+    }
+}
@@ -0,0 +1,300 @@
+use ffi::*;
+use format::Format;
+use clock_time::ClockTime;
+use seek::{SeekFlags, SeekType};
+
+use std::fmt::{self, Debug, Formatter};
+use std::mem;
+
+unsafe impl Send for Segment {}
+
+/// This helper structure holds the relevant values for tracking the region
+/// of interest in a media file, called a segment.
+///
+/// The structure can be used for two purposes:
+///
+/// - performing seeks (handling seek events)
+/// - tracking playback regions (handling newsegment events)
+///
+/// A segment has a format, the unit of all its values, and values are
+/// None when they are not set, like a stop position of a stream of
+/// unknown length. For segments in Format::Time the values are
+/// nanoseconds and ClockTimes can be passed directly to the conversion
+/// functions, their clock time variants also return ClockTimes.
+///
+/// For synchronisation against a clock, the running time of a timestamp
+/// is needed:
+///
+/// ```ignore
+/// let segment = sample.segment();
+/// let running_time = segment.to_running_clock_time(buffer.pts());
+/// ```
+#[derive(Clone,Copy)]
+pub struct Segment{
+    segment: GstSegment,
+}
+
+fn from_raw(value: u64) -> Option<u64>{
+    if value == GST_CLOCK_TIME_NONE{
+        None
+    }else{
+        Some(value)
+    }
+}
+
+fn to_raw(value: Option<u64>) -> u64{
+    value.unwrap_or(GST_CLOCK_TIME_NONE)
+}
+
+impl Segment{
+    /// Creates a new segment with the given format. The segment has a
+    /// rate of 1.0 and starts at 0 without a stop position.
+    pub fn new(format: Format) -> Segment{
+        unsafe{
+            let mut segment: GstSegment = mem::zeroed();
+            gst_segment_init(&mut segment, format.into());
+            Segment{ segment: segment }
+        }
+    }
+
+    /// Creates a segment from a copy of a raw GstSegment
+    pub fn new_from_gst_segment(segment: &GstSegment) -> Segment{
+        Segment{ segment: *segment }
+    }
+
+    /// The format of the segment values
+    pub fn format(&self) -> Format{
+        self.segment.format.into()
+    }
+
+    /// The playback rate of the segment
+    pub fn rate(&self) -> f64{
+        self.segment.rate
+    }
+
+    /// Sets the playback rate of the segment
+    pub fn set_rate(&mut self, rate: f64){
+        self.segment.rate = rate;
+    }
+
+    /// The already applied rate to the segment
+    pub fn applied_rate(&self) -> f64{
+        self.segment.applied_rate
+    }
+
+    /// Sets the already applied rate of the segment
+    pub fn set_applied_rate(&mut self, applied_rate: f64){
+        self.segment.applied_rate = applied_rate;
+    }
+
+    /// The start of the segment in buffer timestamp time (PTS)
+    pub fn start(&self) -> Option<u64>{
+        from_raw(self.segment.start)
+    }
+
+    /// Sets the start of the segment, None to unset it
+    pub fn set_start<V: Into<Option<u64>>>(&mut self, start: V){
+        self.segment.start = to_raw(start.into());
+    }
+
+    /// The stop of the segment in buffer timestamp time (PTS)
+    pub fn stop(&self) -> Option<u64>{
+        from_raw(self.segment.stop)
+    }
+
+    /// Sets the stop of the segment, None to unset it
+    pub fn set_stop<V: Into<Option<u64>>>(&mut self, stop: V){
+        self.segment.stop = to_raw(stop.into());
+    }
+
+    /// The stream time of the segment start
+    pub fn time(&self) -> Option<u64>{
+        from_raw(self.segment.time)
+    }
+
+    /// Sets the stream time of the segment start, None to unset it
+    pub fn set_time<V: Into<Option<u64>>>(&mut self, time: V){
+        self.segment.time = to_raw(time.into());
+    }
+
+    /// The running time (plus elapsed time, see offset) of the segment
+    /// start
+    pub fn base(&self) -> Option<u64>{
+        from_raw(self.segment.base)
+    }
+
+    /// Sets the running time of the segment start, None to unset it
+    pub fn set_base<V: Into<Option<u64>>>(&mut self, base: V){
+        self.segment.base = to_raw(base.into());
+    }
+
+    /// The offset to apply to start in running time
+    pub fn offset(&self) -> Option<u64>{
+        from_raw(self.segment.offset)
+    }
+
+    /// Sets the offset to apply to start in running time
+    pub fn set_offset<V: Into<Option<u64>>>(&mut self, offset: V){
+        self.segment.offset = to_raw(offset.into());
+    }
+
+    /// The buffer timestamp position in the segment, used to save the
+    /// last position in the segment
+    pub fn position(&self) -> Option<u64>{
+        from_raw(self.segment.position)
+    }
+
+    /// Sets the last position in the segment, None to unset it
+    pub fn set_position<V: Into<Option<u64>>>(&mut self, position: V){
+        self.segment.position = to_raw(position.into());
+    }
+
+    /// The duration of the segment
+    pub fn duration(&self) -> Option<u64>{
+        from_raw(self.segment.duration)
+    }
+
+    /// Sets the duration of the segment, None if unknown
+    pub fn set_duration<V: Into<Option<u64>>>(&mut self, duration: V){
+        self.segment.duration = to_raw(duration.into());
+    }
+
+    /// Translate position to the total running time using the currently
+    /// configured segment. Position is a value between start and stop.
+    ///
+    /// This function is typically used by elements that need to
+    /// synchronize to the global clock in a pipeline. The running time is
+    /// a constantly increasing value starting from 0.
+    ///
+    /// This function returns None if the position is outside of the
+    /// segment start and stop.
+    pub fn to_running_time<V: Into<Option<u64>>>(&self, position: V) -> Option<u64>{
+        unsafe{
+            from_raw(gst_segment_to_running_time(&self.segment, self.segment.format, to_raw(position.into())))
+        }
+    }
+
+    /// Translate position to stream time using the currently configured
+    /// segment. The position value must be between start and stop value.
+    ///
+    /// This function is typically used by elements that need to operate
+    /// on the stream time of the buffers it receives, such as effect
+    /// plugins. In those use cases, position is typically the buffer
+    /// timestamp or clock time that one wants to convert to the stream
+    /// time. The stream time is always between 0 and the total duration
+    /// of the media stream.
+    ///
+    /// Returns None if the position is outside the segment.
+    pub fn to_stream_time<V: Into<Option<u64>>>(&self, position: V) -> Option<u64>{
+        unsafe{
+            from_raw(gst_segment_to_stream_time(&self.segment, self.segment.format, to_raw(position.into())))
+        }
+    }
+
+    /// Convert running_time into a position in the segment so that
+    /// `to_running_time` with that position returns running_time.
+    ///
+    /// Returns None if running_time is before the segment start.
+    pub fn to_position<V: Into<Option<u64>>>(&self, running_time: V) -> Option<u64>{
+        unsafe{
+            from_raw(gst_segment_to_position(&self.segment, self.segment.format, to_raw(running_time.into())))
+        }
+    }
+
+    /// Like `to_running_time` for segments in Format::Time. Returns
+    /// ClockTime::NONE if the position is outside of the segment or if
+    /// the segment is in a different format.
+    pub fn to_running_clock_time(&self, position: ClockTime) -> ClockTime{
+        if self.format() == Format::Time{
+            self.to_running_time(position).into()
+        }else{
+            ClockTime::NONE
+        }
+    }
+
+    /// Like `to_stream_time` for segments in Format::Time. Returns
+    /// ClockTime::NONE if the position is outside of the segment or if
+    /// the segment is in a different format.
+    pub fn to_stream_clock_time(&self, position: ClockTime) -> ClockTime{
+        if self.format() == Format::Time{
+            self.to_stream_time(position).into()
+        }else{
+            ClockTime::NONE
+        }
+    }
+
+    /// Like `to_position` for segments in Format::Time. Returns
+    /// ClockTime::NONE if running_time is before the segment start or if
+    /// the segment is in a different format.
+    pub fn to_clock_time_position(&self, running_time: ClockTime) -> ClockTime{
+        if self.format() == Format::Time{
+            self.to_position(running_time).into()
+        }else{
+            ClockTime::NONE
+        }
+    }
+
+    /// Clip the given start and stop values to the segment boundaries.
+    ///
+    /// If the function returns None, start and stop are known to fall
+    /// outside of the segment and the buffer should be dropped.
+    ///
+    /// Note that when stop is None, the clipped stop value will also be
+    /// None, but the clipped start might still need to be clipped to the
+    /// segment start.
+    pub fn clip<S: Into<Option<u64>>, E: Into<Option<u64>>>(&self, start: S, stop: E) -> Option<(Option<u64>, Option<u64>)>{
+        unsafe{
+            let mut clip_start = 0;
+            let mut clip_stop = 0;
+            if gst_segment_clip(&self.segment, self.segment.format,
+                                to_raw(start.into()), to_raw(stop.into()),
+                                &mut clip_start, &mut clip_stop) != 0{
+                Some((from_raw(clip_start), from_raw(clip_stop)))
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Update the segment structure with the field values of a seek
+    /// event.
+    ///
+    /// The position of the segment is updated to the new start when
+    /// start_type is SeekType::Set, unless the FLUSH flag is missing, and
+    /// to the stop when playing backwards.
+    ///
+    /// Returns None if the seek could not be performed, otherwise
+    /// Some(update) where update is true if the seek updated the current
+    /// position.
+    pub fn do_seek<S: Into<Option<u64>>, E: Into<Option<u64>>>(&mut self, rate: f64, format: Format, flags: SeekFlags, start_type: SeekType, start: S, stop_type: SeekType, stop: E) -> Option<bool>{
+        unsafe{
+            let mut update = 0;
+            if gst_segment_do_seek(&mut self.segment, rate, format.into(), flags.bits(),
+                                   start_type.into(), to_raw(start.into()),
+                                   stop_type.into(), to_raw(stop.into()),
+                                   &mut update) != 0{
+                Some(update != 0)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the raw GstSegment
+    pub fn gst_segment(&self) -> &GstSegment{
+        &self.segment
+    }
+
+    /// Returns a mutable reference to the raw GstSegment
+    pub fn gst_segment_mut(&mut self) -> &mut GstSegment{
+        &mut self.segment
+    }
+}
+
+impl Debug for Segment{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>{
+        write!(fmt, "Segment{{format: {:?}, rate: {}, applied_rate: {}, base: {:?}, offset: {:?}, start: {:?}, stop: {:?}, time: {:?}, position: {:?}, duration: {:?}}}",
+            self.format(), self.rate(), self.applied_rate(), self.base(), self.offset(),
+            self.start(), self.stop(), self.time(), self.position(), self.duration())
+    }
+}
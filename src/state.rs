@@ -0,0 +1,163 @@
+use ffi::*;
+use error::Error;
+
+use std::fmt::{self, Display, Formatter};
+use std::error;
+
+/// The possible states an element can be in. States can be changed using
+/// `Element::set_state()` and checked using `Element::get_state()`.
+#[derive(Copy,Clone,Debug,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub enum State{
+    /// No pending state
+    VoidPending,
+    /// The initial state of an element
+    Null,
+    /// The element is ready to go to PAUSED
+    Ready,
+    /// The element is PAUSED, it is ready to accept and process data.
+    /// Sink elements however only accept one buffer and then block.
+    Paused,
+    /// The element is PLAYING, the clock is running and the data is
+    /// flowing.
+    Playing,
+}
+
+impl From<GstState> for State{
+    fn from(state: GstState) -> State{
+        match state{
+            GST_STATE_VOID_PENDING => State::VoidPending,
+            GST_STATE_NULL => State::Null,
+            GST_STATE_READY => State::Ready,
+            GST_STATE_PAUSED => State::Paused,
+            GST_STATE_PLAYING => State::Playing,
+        }
+    }
+}
+
+impl From<State> for GstState{
+    fn from(state: State) -> GstState{
+        match state{
+            State::VoidPending => GST_STATE_VOID_PENDING,
+            State::Null => GST_STATE_NULL,
+            State::Ready => GST_STATE_READY,
+            State::Paused => GST_STATE_PAUSED,
+            State::Playing => GST_STATE_PLAYING,
+        }
+    }
+}
+
+/// The possible successful return values from a state change function
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum StateChangeSuccess{
+    /// The state change succeeded
+    Success,
+    /// The state change will happen asynchronously
+    Async,
+    /// The state change succeeded but the element cannot produce data in
+    /// PAUSED. This typically happens with live sources.
+    NoPreroll,
+}
+
+/// A failed state change. When available it carries the error that the
+/// failing element posted on the bus, explaining why the state change
+/// failed.
+#[derive(Debug)]
+pub struct StateChangeError{
+    error: Option<Error>,
+    debug: Option<String>,
+    src_path: Option<String>,
+}
+
+impl StateChangeError{
+    /// Creates a state change error without further details
+    pub fn new() -> StateChangeError{
+        StateChangeError{
+            error: None,
+            debug: None,
+            src_path: None,
+        }
+    }
+
+    /// Creates a state change error from the error posted by an element
+    pub fn new_with_error(error: Error, debug: Option<String>, src_path: Option<String>) -> StateChangeError{
+        StateChangeError{
+            error: Some(error),
+            debug: debug,
+            src_path: src_path,
+        }
+    }
+
+    /// The error posted by the element that failed the state change
+    pub fn error(&self) -> Option<&Error>{
+        self.error.as_ref()
+    }
+
+    /// Additional debug information posted along with the error
+    pub fn debug(&self) -> Option<&str>{
+        self.debug.as_ref().map(|debug| debug.as_ref())
+    }
+
+    /// Path of the element that posted the error, like "/pipeline0/filesrc0"
+    pub fn src_path(&self) -> Option<&str>{
+        self.src_path.as_ref().map(|name| name.as_ref())
+    }
+}
+
+impl Display for StateChangeError{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>{
+        match (&self.error, &self.src_path){
+            (&Some(ref error), &Some(ref src_path)) => write!(fmt, "State change failed: {} (from {})", error.message(), src_path),
+            (&Some(ref error), &None) => write!(fmt, "State change failed: {}", error.message()),
+            _ => fmt.write_str("State change failed"),
+        }
+    }
+}
+
+impl error::Error for StateChangeError{
+    fn description(&self) -> &str{
+        "State change failed"
+    }
+}
+
+/// Snapshot of the states an element caches, as opposed to
+/// `Element::get_state` this never waits for an asynchronous state change
+/// to complete. Only the result of the last state change is kept by
+/// GStreamer, earlier results are lost.
+#[derive(Debug)]
+pub struct CachedState{
+    /// The state the element is currently in
+    pub current: State,
+    /// The next state the element is going to, VoidPending if no state
+    /// change is in progress
+    pub next: State,
+    /// The final state the element is going to, VoidPending if no state
+    /// change is in progress
+    pub pending: State,
+    /// The state the application last requested, this is the state the
+    /// element will end up in once all state changes are done
+    pub target: State,
+    /// The result of the last state change. GStreamer only keeps the
+    /// return of the most recent state change, there's no longer history
+    /// available
+    pub last_return: Result<StateChangeSuccess, StateChangeError>,
+}
+
+/// Converts a raw state change return into a result
+pub fn state_change_result(ret: GstStateChangeReturn) -> Result<StateChangeSuccess, StateChangeError>{
+    match ret{
+        GST_STATE_CHANGE_SUCCESS => Ok(StateChangeSuccess::Success),
+        GST_STATE_CHANGE_ASYNC => Ok(StateChangeSuccess::Async),
+        GST_STATE_CHANGE_NO_PREROLL => Ok(StateChangeSuccess::NoPreroll),
+        _ => Err(StateChangeError::new()),
+    }
+}
+
+/// Converts a state change result back into a raw state change return
+pub fn state_change_return(ret: &Result<StateChangeSuccess, StateChangeError>) -> GstStateChangeReturn{
+    match *ret{
+        Ok(StateChangeSuccess::Success) => GST_STATE_CHANGE_SUCCESS,
+        Ok(StateChangeSuccess::Async) => GST_STATE_CHANGE_ASYNC,
+        Ok(StateChangeSuccess::NoPreroll) => GST_STATE_CHANGE_NO_PREROLL,
+        Err(_) => GST_STATE_CHANGE_FAILURE,
+    }
+}
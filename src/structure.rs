@@ -1,11 +1,43 @@
 use ffi::*;
 use util::*;
+use value;
 
+use std::fmt::{self, Debug, Formatter};
+
+use ::{FromGValue, ToGValue};
+
+unsafe impl Send for Structure {}
+
+/// A Structure is a collection of key/value pairs. The keys are expressed
+/// as strings and the values can be of any type that can be converted
+/// from and to a GValue.
+///
+/// In addition to the key/value pairs, a Structure also has a name. The
+/// name starts with a letter and can be filled by letters, numbers and
+/// any of "/-_.:".
+///
+/// Structure is used by various GStreamer subsystems to store information
+/// in a flexible and extensible way: caps, messages, events and queries
+/// all carry structures.
+///
+/// A Structure owns the underlying GstStructure and frees it when dropped.
+/// Structures obtained from caps, messages, events or queries are copies
+/// of the original.
 pub struct Structure{
     structure: *mut GstStructure,
 }
 
+impl Drop for Structure{
+    fn drop(&mut self){
+        unsafe{
+            gst_structure_free(self.structure);
+        }
+    }
+}
+
 impl Structure{
+    /// Creates a Structure from a raw pointer, taking ownership of it.
+    /// The GstStructure must not have a parent.
     pub unsafe fn new_from_gst_structure(structure: *mut GstStructure) -> Option<Structure>{
         if structure!=ptr::null_mut(){
             Some(Structure{
@@ -16,10 +48,255 @@ impl Structure{
         }
     }
 
+    /// Creates a new, empty Structure with the given name.
+    ///
+    /// Returns None if name is not a valid structure name.
+    pub fn new_empty(name: &str) -> Option<Structure>{
+        let cname = CString::new(name).unwrap();
+        unsafe{
+            Structure::new_from_gst_structure(gst_structure_new_empty(cname.as_ptr()))
+        }
+    }
+
+    /// Starts building a new Structure with the given name.
+    ///
+    /// ```ignore
+    /// let structure = Structure::builder("application/x-my-message")
+    ///     .field("count", 10)
+    ///     .field("name", "some name")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// build() returns None if name is not a valid structure name.
+    pub fn builder(name: &str) -> Builder{
+        Builder{ structure: Structure::new_empty(name) }
+    }
+
+    /// Creates a Structure from a string representation.
+    pub fn from_string(string: &str) -> Option<Structure>{
+        let cstring = CString::new(string).unwrap();
+        unsafe{
+            Structure::new_from_gst_structure(gst_structure_from_string(cstring.as_ptr(), ptr::null_mut()))
+        }
+    }
+
+    /// Converts the structure to a human-readable string representation.
+    pub fn to_string(&self) -> String{
+        unsafe{
+            let cstring = gst_structure_to_string(self.structure);
+            let string = from_c_str!(cstring).to_string();
+            g_free(cstring as gpointer);
+            string
+        }
+    }
+
     pub fn name(&self) -> &str{
         unsafe{
             let cname = gst_structure_get_name(self.structure);
             from_c_str!(cname)
         }
     }
+
+    pub fn set_name(&mut self, name: &str){
+        let cname = CString::new(name).unwrap();
+        unsafe{
+            gst_structure_set_name(self.structure, cname.as_ptr());
+        }
+    }
+
+    pub fn has_name(&self, name: &str) -> bool{
+        self.name() == name
+    }
+
+    /// Returns the value of field converted to T or None if the field
+    /// doesn't exist or is of a different type.
+    pub fn get<T: FromGValue>(&self, field: &str) -> Option<T>{
+        let cfield = CString::new(field).unwrap();
+        unsafe{
+            let value = gst_structure_get_value(self.structure, cfield.as_ptr());
+            if value != ptr::null(){
+                T::from_gvalue(&*value)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Sets the field with the given name to value. If the field does
+    /// not exist, it is created. If the field exists, the previous value
+    /// is replaced.
+    pub fn set<T: ToGValue>(&mut self, field: &str, value: T){
+        let cfield = CString::new(field).unwrap();
+        unsafe{
+            let mut value = value.to_gvalue();
+            gst_structure_take_value(self.structure, cfield.as_ptr(), &mut value);
+        }
+    }
+
+    /// Check if the structure contains a field named field.
+    pub fn has_field(&self, field: &str) -> bool{
+        let cfield = CString::new(field).unwrap();
+        unsafe{
+            gst_structure_has_field(self.structure, cfield.as_ptr()) != 0
+        }
+    }
+
+    /// Returns the GType of the field or None if the field doesn't exist.
+    pub fn field_type(&self, field: &str) -> Option<GType>{
+        let cfield = CString::new(field).unwrap();
+        unsafe{
+            let ty = gst_structure_get_field_type(self.structure, cfield.as_ptr());
+            if ty != 0 { Some(ty) } else { None }
+        }
+    }
+
+    /// Removes the field with the given name. If the field does not
+    /// exist, the call is ignored.
+    pub fn remove_field(&mut self, field: &str){
+        let cfield = CString::new(field).unwrap();
+        unsafe{
+            gst_structure_remove_field(self.structure, cfield.as_ptr());
+        }
+    }
+
+    /// Removes all fields in the structure.
+    pub fn remove_all_fields(&mut self){
+        unsafe{
+            gst_structure_remove_all_fields(self.structure);
+        }
+    }
+
+    /// Get the number of fields in the structure.
+    pub fn n_fields(&self) -> u32{
+        unsafe{
+            gst_structure_n_fields(self.structure) as u32
+        }
+    }
+
+    /// Get the name of the field at index or None if the index is out
+    /// of range.
+    pub fn nth_field_name(&self, index: u32) -> Option<&str>{
+        if index >= self.n_fields(){
+            return None;
+        }
+        unsafe{
+            Some(from_c_str!(gst_structure_nth_field_name(self.structure, index)))
+        }
+    }
+
+    /// Returns an iterator over the field names of the structure.
+    pub fn fields<'a>(&'a self) -> Fields<'a>{
+        Fields{
+            structure: self,
+            idx: 0,
+            n_fields: self.n_fields(),
+        }
+    }
+
+    pub unsafe fn gst_structure(&self) -> *const GstStructure{
+        self.structure
+    }
+
+    pub unsafe fn gst_structure_mut(&mut self) -> *mut GstStructure{
+        self.structure
+    }
+}
+
+impl Clone for Structure{
+    fn clone(&self) -> Structure{
+        unsafe{
+            Structure{ structure: gst_structure_copy(self.structure) }
+        }
+    }
+}
+
+impl PartialEq for Structure{
+    fn eq(&self, other: &Structure) -> bool{
+        unsafe{
+            gst_structure_is_equal(self.structure, other.structure) != 0
+        }
+    }
+}
+
+impl Eq for Structure{}
+
+impl Debug for Structure{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>{
+        fmt.write_str(&self.to_string())
+    }
+}
+
+impl ::Transfer<GstStructure> for Structure{
+    unsafe fn transfer(self) -> *mut GstStructure{
+        let structure = self.structure;
+        mem::forget(self);
+        structure
+    }
+}
+
+impl FromGValue for Structure{
+    fn from_gvalue(gvalue: &GValue) -> Option<Structure>{
+        unsafe{
+            if value::holds(gvalue, gst_structure_get_type()){
+                let structure = g_value_get_boxed(gvalue) as *const GstStructure;
+                if structure != ptr::null(){
+                    Structure::new_from_gst_structure(gst_structure_copy(structure))
+                }else{
+                    None
+                }
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for Structure{
+    fn to_gvalue(&self) -> GValue{
+        let mut gvalue = value::new_gvalue(unsafe{ gst_structure_get_type() });
+        unsafe{ g_value_set_boxed(&mut gvalue, self.structure as gconstpointer) };
+        gvalue
+    }
+}
+
+/// Iterator over the field names of a Structure
+pub struct Fields<'a>{
+    structure: &'a Structure,
+    idx: u32,
+    n_fields: u32,
+}
+
+impl<'a> Iterator for Fields<'a>{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str>{
+        if self.idx < self.n_fields{
+            let name = self.structure.nth_field_name(self.idx);
+            self.idx += 1;
+            name
+        }else{
+            None
+        }
+    }
+}
+
+/// Builder for a Structure, see `Structure::builder`
+pub struct Builder{
+    structure: Option<Structure>,
+}
+
+impl Builder{
+    /// Sets the field with the given name to value
+    pub fn field<T: ToGValue>(mut self, field: &str, value: T) -> Builder{
+        if let Some(ref mut structure) = self.structure{
+            structure.set(field, value);
+        }
+        self
+    }
+
+    /// Returns the built Structure or None if the name was invalid
+    pub fn build(self) -> Option<Structure>{
+        self.structure
+    }
 }
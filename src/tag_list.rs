@@ -0,0 +1,409 @@
+use ffi::*;
+use util::*;
+use std::ops::{Deref, DerefMut};
+
+use sample::Sample;
+use value::{self, Value};
+use reference::Reference;
+use miniobject::MiniObject;
+
+use ::{FromGValue, ToGValue};
+
+/// Commonly used title (String)
+pub const TAG_TITLE: &'static str = "title";
+/// Person(s) responsible for the recording (String)
+pub const TAG_ARTIST: &'static str = "artist";
+/// Album containing this data (String)
+pub const TAG_ALBUM: &'static str = "album";
+/// Genre this data belongs to (String)
+pub const TAG_GENRE: &'static str = "genre";
+/// Free text commenting the data (String)
+pub const TAG_COMMENT: &'static str = "comment";
+/// Track number inside a collection (u32)
+pub const TAG_TRACK_NUMBER: &'static str = "track-number";
+/// Length in GStreamer time units, nanoseconds (u64)
+pub const TAG_DURATION: &'static str = "duration";
+/// Exact or average bitrate in bits/s (u32)
+pub const TAG_BITRATE: &'static str = "bitrate";
+/// Nominal bitrate in bits/s (u32)
+pub const TAG_NOMINAL_BITRATE: &'static str = "nominal-bitrate";
+/// Codec the data is stored in (String)
+pub const TAG_CODEC: &'static str = "codec";
+/// Codec the audio data is stored in (String)
+pub const TAG_AUDIO_CODEC: &'static str = "audio-codec";
+/// Codec the video data is stored in (String)
+pub const TAG_VIDEO_CODEC: &'static str = "video-codec";
+/// Codec/format the subtitle data is stored in (String)
+pub const TAG_SUBTITLE_CODEC: &'static str = "subtitle-codec";
+/// Container format the data is stored in (String)
+pub const TAG_CONTAINER_FORMAT: &'static str = "container-format";
+/// ISO-639-2 or ISO-639-1 code for the language the content is in (String)
+pub const TAG_LANGUAGE_CODE: &'static str = "language-code";
+/// Name of the language the content is in (String)
+pub const TAG_LANGUAGE_NAME: &'static str = "language-name";
+/// Image related to this stream, e.g. a cover image (Sample)
+pub const TAG_IMAGE: &'static str = "image";
+/// Image that is meant for preview purposes, e.g. a thumbnail (Sample)
+pub const TAG_PREVIEW_IMAGE: &'static str = "preview-image";
+/// Date and time the data was created (GstDateTime)
+pub const TAG_DATE_TIME: &'static str = "datetime";
+
+unsafe impl Send for TagList {}
+
+/// A TagList is a list of tags and values used to describe the metadata
+/// of a stream, like its title, artist or the codecs in use. Every tag
+/// can hold one or more values of the type registered for it.
+///
+/// Tag lists are received from the bus in Tag messages and travel through
+/// the pipeline in Tag events:
+///
+/// ```ignore
+/// if let Message::TagParsed{ref tags, ..} = message.parse(){
+///     println!("title: {:?}", tags.title());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TagList{
+    taglist: MiniObject
+}
+
+impl TagList{
+    pub unsafe fn new(taglist: *mut GstTagList) -> Option<TagList>{
+        MiniObject::new_from_gst_miniobject(taglist as *mut GstMiniObject)
+            .map(|miniobject| TagList{ taglist: miniobject })
+    }
+
+    /// Creates a new empty tag list.
+    pub fn new_empty() -> TagList{
+        unsafe{
+            TagList::new(gst_tag_list_new_empty()).unwrap()
+        }
+    }
+
+    /// Deserializes a tag list from its string representation as
+    /// returned by `to_string`.
+    pub fn from_string(string: &str) -> Option<TagList>{
+        let cstring = CString::new(string).unwrap();
+        unsafe{
+            TagList::new(gst_tag_list_new_from_string(cstring.as_ptr()))
+        }
+    }
+
+    /// Serializes a tag list to a string.
+    pub fn to_string(&self) -> String{
+        unsafe{
+            let cstring = gst_tag_list_to_string(self.gst_tag_list());
+            let string = from_c_str!(cstring).to_string();
+            g_free(cstring as gpointer);
+            string
+        }
+    }
+
+    pub fn is_writable(&self) -> bool{
+        unsafe{
+            gst_mini_object_is_writable(self.taglist.gst_miniobject()) != 0
+        }
+    }
+
+    /// Returns a writable version of the tag list, copying it if it's
+    /// shared.
+    pub fn make_writable(self) -> TagList{
+        TagList{ taglist: self.taglist.make_writable() }
+    }
+
+    /// Checks if the tag list is empty.
+    pub fn is_empty(&self) -> bool{
+        unsafe{
+            gst_tag_list_is_empty(self.gst_tag_list()) != 0
+        }
+    }
+
+    /// Get the number of tags in the list.
+    pub fn n_tags(&self) -> u32{
+        unsafe{
+            gst_tag_list_n_tags(self.gst_tag_list()) as u32
+        }
+    }
+
+    /// Get the name of the tag at index or None if the index is out of
+    /// range.
+    pub fn nth_tag_name(&self, index: u32) -> Option<&str>{
+        if index >= self.n_tags(){
+            return None;
+        }
+        unsafe{
+            Some(from_c_str!(gst_tag_list_nth_tag_name(self.gst_tag_list(), index)))
+        }
+    }
+
+    /// Checks how many values are stored in this tag list for the given
+    /// tag.
+    pub fn tag_size(&self, tag: &str) -> u32{
+        let ctag = CString::new(tag).unwrap();
+        unsafe{
+            gst_tag_list_get_tag_size(self.gst_tag_list(), ctag.as_ptr())
+        }
+    }
+
+    /// Returns the value of tag at index converted to T or None if there's
+    /// no such value or it is of a different type.
+    pub fn get<T: FromGValue>(&self, tag: &str, index: u32) -> Option<T>{
+        let ctag = CString::new(tag).unwrap();
+        unsafe{
+            let value = gst_tag_list_get_value_index(self.gst_tag_list(), ctag.as_ptr(), index);
+            if value != ptr::null(){
+                T::from_gvalue(&*value)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Returns a copy of all the values stored for tag.
+    pub fn values(&self, tag: &str) -> Vec<Value>{
+        (0..self.tag_size(tag)).filter_map(|i| self.get(tag, i)).collect()
+    }
+
+    /// Sets the value of tag according to mode. The tag list is made
+    /// writable first if needed.
+    ///
+    /// The value has to be of the type registered for the tag.
+    pub fn add<T: ToGValue>(&mut self, mode: GstTagMergeMode, tag: &str, value: T){
+        let ctag = CString::new(tag).unwrap();
+        unsafe{
+            self.make_writable_in_place();
+            let mut value = value.to_gvalue();
+            gst_tag_list_add_value(self.gst_tag_list_mut(), mode, ctag.as_ptr(), &value);
+            g_value_unset(&mut value);
+        }
+    }
+
+    /// Removes the given tag from the list. The tag list is made writable
+    /// first if needed.
+    pub fn remove_tag(&mut self, tag: &str){
+        let ctag = CString::new(tag).unwrap();
+        unsafe{
+            self.make_writable_in_place();
+            gst_tag_list_remove_tag(self.gst_tag_list_mut(), ctag.as_ptr());
+        }
+    }
+
+    /// Inserts the tags of from into self using the given mode. The tag
+    /// list is made writable first if needed.
+    pub fn insert(&mut self, from: &TagList, mode: GstTagMergeMode){
+        unsafe{
+            self.make_writable_in_place();
+            gst_tag_list_insert(self.gst_tag_list_mut(), from.gst_tag_list(), mode);
+        }
+    }
+
+    /// Merges the two given lists into a new list. If one of the lists is
+    /// empty, a copy of the other is returned.
+    pub fn merge(&self, other: &TagList, mode: GstTagMergeMode) -> TagList{
+        unsafe{
+            TagList::new(gst_tag_list_merge(self.gst_tag_list(), other.gst_tag_list(), mode)).unwrap()
+        }
+    }
+
+    /// Gets the scope of the tag list, GST_TAG_SCOPE_STREAM by default.
+    pub fn scope(&self) -> GstTagScope{
+        unsafe{
+            gst_tag_list_get_scope(self.gst_tag_list())
+        }
+    }
+
+    /// Sets the scope of the tag list. The tag list is made writable first
+    /// if needed.
+    pub fn set_scope(&mut self, scope: GstTagScope){
+        unsafe{
+            self.make_writable_in_place();
+            gst_tag_list_set_scope(self.gst_tag_list_mut(), scope);
+        }
+    }
+
+    /// Returns an iterator over the tags in the list and copies of their
+    /// values.
+    pub fn iter<'a>(&'a self) -> Iter<'a>{
+        Iter{
+            taglist: self,
+            idx: 0,
+            n_tags: self.n_tags(),
+        }
+    }
+
+    pub fn title(&self) -> Option<String>{
+        self.get(TAG_TITLE, 0)
+    }
+
+    pub fn artist(&self) -> Option<String>{
+        self.get(TAG_ARTIST, 0)
+    }
+
+    pub fn album(&self) -> Option<String>{
+        self.get(TAG_ALBUM, 0)
+    }
+
+    /// Duration in nanoseconds
+    pub fn duration(&self) -> Option<u64>{
+        self.get(TAG_DURATION, 0)
+    }
+
+    /// Bitrate in bits/s
+    pub fn bitrate(&self) -> Option<u32>{
+        self.get(TAG_BITRATE, 0)
+    }
+
+    pub fn codec(&self) -> Option<String>{
+        self.get(TAG_CODEC, 0)
+    }
+
+    pub fn audio_codec(&self) -> Option<String>{
+        self.get(TAG_AUDIO_CODEC, 0)
+    }
+
+    pub fn video_codec(&self) -> Option<String>{
+        self.get(TAG_VIDEO_CODEC, 0)
+    }
+
+    pub fn language_code(&self) -> Option<String>{
+        self.get(TAG_LANGUAGE_CODE, 0)
+    }
+
+    /// Image related to the stream, e.g. a cover image. The sample's caps
+    /// describe the image format.
+    pub fn image(&self) -> Option<Sample>{
+        self.get(TAG_IMAGE, 0)
+    }
+
+    /// Creation date and time as an ISO 8601 string
+    pub fn datetime(&self) -> Option<String>{
+        let ctag = CString::new(TAG_DATE_TIME).unwrap();
+        unsafe{
+            let mut datetime: *mut GstDateTime = ptr::null_mut();
+            if gst_tag_list_get_date_time(self.gst_tag_list(), ctag.as_ptr(), &mut datetime) == 0{
+                return None;
+            }
+            let cstring = gst_date_time_to_iso8601_string(datetime);
+            gst_date_time_unref(datetime);
+            if cstring != ptr::null_mut(){
+                let string = from_c_str!(cstring).to_string();
+                g_free(cstring as gpointer);
+                Some(string)
+            }else{
+                None
+            }
+        }
+    }
+
+    pub unsafe fn gst_tag_list(&self) -> *const GstTagList{
+        self.taglist.gst_miniobject() as *const GstTagList
+    }
+
+    pub unsafe fn gst_tag_list_mut(&mut self) -> *mut GstTagList{
+        self.taglist.gst_miniobject_mut() as *mut GstTagList
+    }
+
+    unsafe fn make_writable_in_place(&mut self){
+        let taglist = ptr::read(&self.taglist);
+        ptr::write(&mut self.taglist, taglist.make_writable());
+    }
+}
+
+impl ::Transfer<GstTagList> for TagList{
+    unsafe fn transfer(self) -> *mut GstTagList{
+        self.taglist.transfer() as *mut GstTagList
+    }
+}
+
+impl Reference for TagList{
+    fn reference(&self) -> TagList{
+        TagList{
+            taglist: self.taglist.reference()
+        }
+    }
+}
+
+impl PartialEq for TagList{
+    fn eq(&self, other: &TagList) -> bool{
+        unsafe{
+            gst_tag_list_is_equal(self.gst_tag_list(), other.gst_tag_list()) != 0
+        }
+    }
+}
+
+impl FromGValue for TagList{
+    fn from_gvalue(gvalue: &GValue) -> Option<TagList>{
+        unsafe{
+            if value::holds(gvalue, gst_tag_list_get_type()){
+                let taglist = g_value_get_boxed(gvalue) as *mut GstMiniObject;
+                if taglist != ptr::null_mut(){
+                    TagList::new(gst_mini_object_ref(taglist) as *mut GstTagList)
+                }else{
+                    None
+                }
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for TagList{
+    fn to_gvalue(&self) -> GValue{
+        let mut gvalue = value::new_gvalue(unsafe{ gst_tag_list_get_type() });
+        unsafe{ g_value_set_boxed(&mut gvalue, self.gst_tag_list() as gconstpointer) };
+        gvalue
+    }
+}
+
+impl AsRef<MiniObject> for TagList{
+    fn as_ref(&self) -> &MiniObject{
+        &self.taglist
+    }
+}
+
+impl AsMut<MiniObject> for TagList{
+    fn as_mut(&mut self) -> &mut MiniObject{
+        &mut self.taglist
+    }
+}
+
+impl From<TagList> for MiniObject{
+    fn from(t: TagList) -> MiniObject{
+        t.taglist
+    }
+}
+
+impl Deref for TagList{
+    type Target = MiniObject;
+    fn deref(&self) -> &MiniObject{
+        &self.taglist
+    }
+}
+
+impl DerefMut for TagList{
+    fn deref_mut(&mut self) -> &mut MiniObject{
+        &mut self.taglist
+    }
+}
+
+/// Iterator over the tags of a TagList and their values
+pub struct Iter<'a>{
+    taglist: &'a TagList,
+    idx: u32,
+    n_tags: u32,
+}
+
+impl<'a> Iterator for Iter<'a>{
+    type Item = (&'a str, Vec<Value>);
+
+    fn next(&mut self) -> Option<(&'a str, Vec<Value>)>{
+        if self.idx < self.n_tags{
+            let tag = self.taglist.nth_tag_name(self.idx);
+            self.idx += 1;
+            tag.map(|tag| (tag, self.taglist.values(tag)))
+        }else{
+            None
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use ffi::*;
+use util::*;
+use std::ops::{Deref, DerefMut};
+
+use tag_list::TagList;
+use reference::Reference;
+use miniobject::MiniObject;
+
+unsafe impl Send for Toc {}
+
+/// A table of contents, like the chapters of a movie or the tracks of a
+/// CD, as received from the bus in Toc messages.
+///
+/// ```ignore
+/// if let Message::TocParsed{ref toc, ..} = message.parse(){
+///     println!("toc tags: {:?}", toc.tags());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Toc{
+    toc: MiniObject
+}
+
+impl Toc{
+    pub unsafe fn new(toc: *mut GstToc) -> Option<Toc>{
+        MiniObject::new_from_gst_miniobject(toc as *mut GstMiniObject)
+            .map(|miniobject| Toc{ toc: miniobject })
+    }
+
+    /// Creates a new, empty toc with the given scope.
+    pub fn new_with_scope(scope: GstTocScope) -> Toc{
+        unsafe{
+            Toc::new(gst_toc_new(scope)).unwrap()
+        }
+    }
+
+    /// The scope of the toc, GST_TOC_SCOPE_GLOBAL if it's valid for the
+    /// whole stream or GST_TOC_SCOPE_CURRENT if it only applies to the
+    /// current edition.
+    pub fn scope(&self) -> GstTocScope{
+        unsafe{
+            gst_toc_get_scope(self.gst_toc())
+        }
+    }
+
+    /// Returns the tags of the toc or None if it has no tags
+    pub fn tags(&self) -> Option<TagList>{
+        unsafe{
+            let tags = gst_toc_get_tags(self.gst_toc());
+            if tags != ptr::null_mut(){
+                TagList::new(gst_mini_object_ref(tags as *mut GstMiniObject) as *mut GstTagList)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Dumps the toc to the GStreamer debug log
+    pub fn dump(&mut self){
+        unsafe{
+            gst_toc_dump(self.gst_toc_mut());
+        }
+    }
+
+    pub unsafe fn gst_toc(&self) -> *const GstToc{
+        self.toc.gst_miniobject() as *const GstToc
+    }
+
+    pub unsafe fn gst_toc_mut(&mut self) -> *mut GstToc{
+        self.toc.gst_miniobject_mut() as *mut GstToc
+    }
+}
+
+impl ::Transfer<GstToc> for Toc{
+    unsafe fn transfer(self) -> *mut GstToc{
+        self.toc.transfer() as *mut GstToc
+    }
+}
+
+impl Reference for Toc{
+    fn reference(&self) -> Toc{
+        Toc{
+            toc: self.toc.reference()
+        }
+    }
+}
+
+impl AsRef<MiniObject> for Toc{
+    fn as_ref(&self) -> &MiniObject{
+        &self.toc
+    }
+}
+
+impl AsMut<MiniObject> for Toc{
+    fn as_mut(&mut self) -> &mut MiniObject{
+        &mut self.toc
+    }
+}
+
+impl From<Toc> for MiniObject{
+    fn from(t: Toc) -> MiniObject{
+        t.toc
+    }
+}
+
+impl Deref for Toc{
+    type Target = MiniObject;
+    fn deref(&self) -> &MiniObject{
+        &self.toc
+    }
+}
+
+impl DerefMut for Toc{
+    fn deref_mut(&mut self) -> &mut MiniObject{
+        &mut self.toc
+    }
+}
@@ -0,0 +1,691 @@
+use ffi::*;
+use util::*;
+
+use std::fmt::{self, Debug, Formatter};
+
+use object::{Object, Property, FromProperty};
+
+use ::{FromGValue, ToGValue};
+
+// Fundamental GTypes, defined as macros in gtype.h
+pub const G_TYPE_BOOLEAN: GType = 5 << 2;
+pub const G_TYPE_INT: GType = 6 << 2;
+pub const G_TYPE_UINT: GType = 7 << 2;
+pub const G_TYPE_INT64: GType = 10 << 2;
+pub const G_TYPE_UINT64: GType = 11 << 2;
+pub const G_TYPE_ENUM: GType = 12 << 2;
+pub const G_TYPE_FLAGS: GType = 13 << 2;
+pub const G_TYPE_FLOAT: GType = 14 << 2;
+pub const G_TYPE_DOUBLE: GType = 15 << 2;
+pub const G_TYPE_STRING: GType = 16 << 2;
+
+/// Returns true if the value holds a value of type ty or of a type
+/// derived from it
+pub fn holds(value: &GValue, ty: GType) -> bool{
+    unsafe{
+        g_type_check_value_holds(value as *const GValue as *mut GValue, ty) != 0
+    }
+}
+
+/// Returns a GValue initialized to type ty
+pub fn new_gvalue(ty: GType) -> GValue{
+    unsafe{
+        let mut value: GValue = mem::zeroed();
+        g_value_init(&mut value, ty);
+        value
+    }
+}
+
+macro_rules! gvalue_impl(
+    ($t: ty, $gtype: expr, $get: ident, $set: ident) => (
+        impl FromGValue for $t{
+            fn from_gvalue(value: &GValue) -> Option<$t>{
+                if holds(value, $gtype){
+                    unsafe{ Some($get(value) as $t) }
+                }else{
+                    None
+                }
+            }
+        }
+
+        impl ToGValue for $t{
+            fn to_gvalue(&self) -> GValue{
+                let mut value = new_gvalue($gtype);
+                unsafe{ $set(&mut value, *self as _) };
+                value
+            }
+        }
+    )
+);
+
+gvalue_impl!(i32, G_TYPE_INT, g_value_get_int, g_value_set_int);
+gvalue_impl!(u32, G_TYPE_UINT, g_value_get_uint, g_value_set_uint);
+gvalue_impl!(i64, G_TYPE_INT64, g_value_get_int64, g_value_set_int64);
+gvalue_impl!(u64, G_TYPE_UINT64, g_value_get_uint64, g_value_set_uint64);
+gvalue_impl!(f32, G_TYPE_FLOAT, g_value_get_float, g_value_set_float);
+gvalue_impl!(f64, G_TYPE_DOUBLE, g_value_get_double, g_value_set_double);
+
+impl FromGValue for bool{
+    fn from_gvalue(value: &GValue) -> Option<bool>{
+        if holds(value, G_TYPE_BOOLEAN){
+            unsafe{ Some(g_value_get_boolean(value) != 0) }
+        }else{
+            None
+        }
+    }
+}
+
+impl ToGValue for bool{
+    fn to_gvalue(&self) -> GValue{
+        let mut value = new_gvalue(G_TYPE_BOOLEAN);
+        unsafe{ g_value_set_boolean(&mut value, *self as gboolean) };
+        value
+    }
+}
+
+impl FromGValue for String{
+    fn from_gvalue(value: &GValue) -> Option<String>{
+        if holds(value, G_TYPE_STRING){
+            unsafe{
+                let string = g_value_get_string(value);
+                if string != ptr::null(){
+                    Some(from_c_str!(string).to_string())
+                }else{
+                    None
+                }
+            }
+        }else{
+            None
+        }
+    }
+}
+
+impl<'a> ToGValue for &'a str{
+    fn to_gvalue(&self) -> GValue{
+        let cstring = CString::new(*self).unwrap();
+        let mut value = new_gvalue(G_TYPE_STRING);
+        unsafe{ g_value_set_string(&mut value, cstring.as_ptr()) };
+        value
+    }
+}
+
+impl ToGValue for String{
+    fn to_gvalue(&self) -> GValue{
+        self.as_str().to_gvalue()
+    }
+}
+
+/// An owned, dynamically typed value. Value wraps a GValue and is used
+/// to read and write fields of structures and object properties whose
+/// type is only known at runtime.
+///
+/// Any type implementing ToGValue can be converted into a Value with
+/// `Value::new` and read back with `Value::get`:
+///
+/// ```ignore
+/// let framerate = Value::new(Fraction::new(30, 1));
+/// let fraction: Option<Fraction> = framerate.get();
+/// ```
+pub struct Value{
+    value: GValue
+}
+
+unsafe impl Send for Value {}
+
+impl Drop for Value{
+    fn drop(&mut self){
+        unsafe{
+            if self.value.g_type != 0{
+                g_value_unset(&mut self.value);
+            }
+        }
+    }
+}
+
+impl Value{
+    /// Creates a new Value holding a copy of t
+    pub fn new<T: ToGValue>(t: T) -> Value{
+        Value{ value: t.to_gvalue() }
+    }
+
+    /// Creates a Value from an initialized GValue, taking ownership
+    /// of its contents.
+    pub unsafe fn new_from_gvalue(value: GValue) -> Value{
+        Value{ value: value }
+    }
+
+    /// Tries to create a Value of type ty from its string representation
+    pub fn deserialize(ty: GType, string: &str) -> Option<Value>{
+        let cstring = CString::new(string).unwrap();
+        let mut value = new_gvalue(ty);
+        unsafe{
+            if gst_value_deserialize(&mut value, cstring.as_ptr()) != 0{
+                Some(Value{ value: value })
+            }else{
+                g_value_unset(&mut value);
+                None
+            }
+        }
+    }
+
+    /// Returns the contents of the value converted to T or None if the
+    /// value holds a different type
+    pub fn get<T: FromGValue>(&self) -> Option<T>{
+        T::from_gvalue(&self.value)
+    }
+
+    /// Returns true if the value holds a value of type ty
+    pub fn holds(&self, ty: GType) -> bool{
+        holds(&self.value, ty)
+    }
+
+    pub fn gtype(&self) -> GType{
+        self.value.g_type
+    }
+
+    pub fn type_name(&self) -> &str{
+        unsafe{
+            from_c_str!(g_type_name(self.value.g_type))
+        }
+    }
+
+    /// Returns the string representation of the value as used in caps
+    /// and structure strings or None if the value can't be serialized
+    pub fn serialize(&self) -> Option<String>{
+        unsafe{
+            let cstring = gst_value_serialize(&self.value);
+            if cstring != ptr::null_mut(){
+                let string = from_c_str!(cstring).to_string();
+                g_free(cstring as gpointer);
+                Some(string)
+            }else{
+                None
+            }
+        }
+    }
+
+    /// Returns true if the value is fixed, i.e. it's not a range or a list
+    pub fn is_fixed(&self) -> bool{
+        unsafe{
+            gst_value_is_fixed(&self.value) != 0
+        }
+    }
+
+    pub unsafe fn gvalue(&self) -> *const GValue{
+        &self.value
+    }
+
+    pub unsafe fn gvalue_mut(&mut self) -> *mut GValue{
+        &mut self.value
+    }
+}
+
+impl Clone for Value{
+    fn clone(&self) -> Value{
+        Value::new(self)
+    }
+}
+
+impl PartialEq for Value{
+    fn eq(&self, other: &Value) -> bool{
+        unsafe{
+            gst_value_compare(&self.value, &other.value) == 0
+        }
+    }
+}
+
+impl Debug for Value{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>{
+        match self.serialize(){
+            Some(string) => fmt.write_fmt(format_args!("Value<{}: {}>", self.type_name(), string)),
+            None => fmt.write_fmt(format_args!("Value<{}>", self.type_name())),
+        }
+    }
+}
+
+impl FromGValue for Value{
+    fn from_gvalue(value: &GValue) -> Option<Value>{
+        let mut copy = new_gvalue(value.g_type);
+        unsafe{ g_value_copy(value, &mut copy) };
+        Some(Value{ value: copy })
+    }
+}
+
+impl ToGValue for Value{
+    fn to_gvalue(&self) -> GValue{
+        let mut copy = new_gvalue(self.value.g_type);
+        unsafe{ g_value_copy(&self.value, &mut copy) };
+        copy
+    }
+}
+
+impl Property for Value{
+    type Target = Value;
+    #[inline]
+    fn set_to(&self, key: &str, e: &mut Object){
+        e.set_value(key, self)
+    }
+}
+
+/// Panics if the object has no property with the given name, use
+/// `Object::get_value` to handle that case.
+impl FromProperty for Value{
+    fn from_property(value: Value) -> Value{
+        value
+    }
+
+    fn get_from(key: &str, e: &Object) -> Value{
+        e.get_value(key).expect("Object has no property with this name")
+    }
+}
+
+impl<'a> Property for &'a Value{
+    type Target = Value;
+    #[inline]
+    fn set_to(&self, key: &str, e: &mut Object){
+        e.set_value(key, self)
+    }
+}
+
+/// A fraction, as used in caps for framerates and pixel aspect ratios
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct Fraction{
+    pub numer: i32,
+    pub denom: i32,
+}
+
+impl Fraction{
+    pub fn new(numer: i32, denom: i32) -> Fraction{
+        Fraction{ numer: numer, denom: denom }
+    }
+}
+
+impl FromGValue for Fraction{
+    fn from_gvalue(value: &GValue) -> Option<Fraction>{
+        unsafe{
+            if holds(value, gst_fraction_get_type()){
+                Some(Fraction{
+                    numer: gst_value_get_fraction_numerator(value),
+                    denom: gst_value_get_fraction_denominator(value),
+                })
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for Fraction{
+    fn to_gvalue(&self) -> GValue{
+        unsafe{
+            let mut value = new_gvalue(gst_fraction_get_type());
+            gst_value_set_fraction(&mut value, self.numer, self.denom);
+            value
+        }
+    }
+}
+
+/// A range of integers with an optional step, [min, max, step]
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct IntRange{
+    pub min: i32,
+    pub max: i32,
+    pub step: i32,
+}
+
+impl IntRange{
+    pub fn new(min: i32, max: i32) -> IntRange{
+        IntRange{ min: min, max: max, step: 1 }
+    }
+
+    pub fn new_with_step(min: i32, max: i32, step: i32) -> IntRange{
+        IntRange{ min: min, max: max, step: step }
+    }
+}
+
+impl FromGValue for IntRange{
+    fn from_gvalue(value: &GValue) -> Option<IntRange>{
+        unsafe{
+            if holds(value, gst_int_range_get_type()){
+                Some(IntRange{
+                    min: gst_value_get_int_range_min(value),
+                    max: gst_value_get_int_range_max(value),
+                    step: gst_value_get_int_range_step(value),
+                })
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for IntRange{
+    fn to_gvalue(&self) -> GValue{
+        unsafe{
+            let mut value = new_gvalue(gst_int_range_get_type());
+            gst_value_set_int_range_step(&mut value, self.min, self.max, self.step);
+            value
+        }
+    }
+}
+
+/// A range of 64 bit integers with an optional step, [min, max, step]
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct Int64Range{
+    pub min: i64,
+    pub max: i64,
+    pub step: i64,
+}
+
+impl Int64Range{
+    pub fn new(min: i64, max: i64) -> Int64Range{
+        Int64Range{ min: min, max: max, step: 1 }
+    }
+
+    pub fn new_with_step(min: i64, max: i64, step: i64) -> Int64Range{
+        Int64Range{ min: min, max: max, step: step }
+    }
+}
+
+impl FromGValue for Int64Range{
+    fn from_gvalue(value: &GValue) -> Option<Int64Range>{
+        unsafe{
+            if holds(value, gst_int64_range_get_type()){
+                Some(Int64Range{
+                    min: gst_value_get_int64_range_min(value),
+                    max: gst_value_get_int64_range_max(value),
+                    step: gst_value_get_int64_range_step(value),
+                })
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for Int64Range{
+    fn to_gvalue(&self) -> GValue{
+        unsafe{
+            let mut value = new_gvalue(gst_int64_range_get_type());
+            gst_value_set_int64_range_step(&mut value, self.min, self.max, self.step);
+            value
+        }
+    }
+}
+
+/// A range of doubles, [min, max]
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct DoubleRange{
+    pub min: f64,
+    pub max: f64,
+}
+
+impl DoubleRange{
+    pub fn new(min: f64, max: f64) -> DoubleRange{
+        DoubleRange{ min: min, max: max }
+    }
+}
+
+impl FromGValue for DoubleRange{
+    fn from_gvalue(value: &GValue) -> Option<DoubleRange>{
+        unsafe{
+            if holds(value, gst_double_range_get_type()){
+                Some(DoubleRange{
+                    min: gst_value_get_double_range_min(value),
+                    max: gst_value_get_double_range_max(value),
+                })
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for DoubleRange{
+    fn to_gvalue(&self) -> GValue{
+        unsafe{
+            let mut value = new_gvalue(gst_double_range_get_type());
+            gst_value_set_double_range(&mut value, self.min, self.max);
+            value
+        }
+    }
+}
+
+/// A range of fractions, [min, max]
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct FractionRange{
+    pub min: Fraction,
+    pub max: Fraction,
+}
+
+impl FractionRange{
+    pub fn new(min: Fraction, max: Fraction) -> FractionRange{
+        FractionRange{ min: min, max: max }
+    }
+}
+
+impl FromGValue for FractionRange{
+    fn from_gvalue(value: &GValue) -> Option<FractionRange>{
+        unsafe{
+            if holds(value, gst_fraction_range_get_type()){
+                let min = gst_value_get_fraction_range_min(value);
+                let max = gst_value_get_fraction_range_max(value);
+                if min == ptr::null() || max == ptr::null(){
+                    return None;
+                }
+                match (Fraction::from_gvalue(&*min), Fraction::from_gvalue(&*max)){
+                    (Some(min), Some(max)) => Some(FractionRange{ min: min, max: max }),
+                    _ => None
+                }
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for FractionRange{
+    fn to_gvalue(&self) -> GValue{
+        unsafe{
+            let mut value = new_gvalue(gst_fraction_range_get_type());
+            let mut min = self.min.to_gvalue();
+            let mut max = self.max.to_gvalue();
+            gst_value_set_fraction_range(&mut value, &min, &max);
+            g_value_unset(&mut min);
+            g_value_unset(&mut max);
+            value
+        }
+    }
+}
+
+/// An unordered list of values, used in caps to express alternatives,
+/// for example `format={ RGB, BGR }`
+#[derive(Debug,Clone,PartialEq)]
+pub struct ValueList(pub Vec<Value>);
+
+impl FromGValue for ValueList{
+    fn from_gvalue(value: &GValue) -> Option<ValueList>{
+        unsafe{
+            if holds(value, gst_value_list_get_type()){
+                let size = gst_value_list_get_size(value);
+                Some(ValueList((0..size)
+                    .map(|i| Value::from_gvalue(&*gst_value_list_get_value(value, i)).unwrap())
+                    .collect()))
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for ValueList{
+    fn to_gvalue(&self) -> GValue{
+        unsafe{
+            let mut value = new_gvalue(gst_value_list_get_type());
+            for v in self.0.iter(){
+                let mut v = v.to_gvalue();
+                gst_value_list_append_and_take_value(&mut value, &mut v);
+            }
+            value
+        }
+    }
+}
+
+/// An ordered array of values, for example `channel-mask=< 1, 2 >`
+#[derive(Debug,Clone,PartialEq)]
+pub struct ValueArray(pub Vec<Value>);
+
+impl FromGValue for ValueArray{
+    fn from_gvalue(value: &GValue) -> Option<ValueArray>{
+        unsafe{
+            if holds(value, gst_value_array_get_type()){
+                let size = gst_value_array_get_size(value);
+                Some(ValueArray((0..size)
+                    .map(|i| Value::from_gvalue(&*gst_value_array_get_value(value, i)).unwrap())
+                    .collect()))
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for ValueArray{
+    fn to_gvalue(&self) -> GValue{
+        unsafe{
+            let mut value = new_gvalue(gst_value_array_get_type());
+            for v in self.0.iter(){
+                let mut v = v.to_gvalue();
+                gst_value_array_append_and_take_value(&mut value, &mut v);
+            }
+            value
+        }
+    }
+}
+
+/// A 64 bit bitmask, used for example for audio channel masks
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct Bitmask(pub u64);
+
+impl FromGValue for Bitmask{
+    fn from_gvalue(value: &GValue) -> Option<Bitmask>{
+        unsafe{
+            if holds(value, gst_bitmask_get_type()){
+                Some(Bitmask(gst_value_get_bitmask(value)))
+            }else{
+                None
+            }
+        }
+    }
+}
+
+impl ToGValue for Bitmask{
+    fn to_gvalue(&self) -> GValue{
+        unsafe{
+            let mut value = new_gvalue(gst_bitmask_get_type());
+            gst_value_set_bitmask(&mut value, self.0);
+            value
+        }
+    }
+}
+
+/// A value of a registered enum type
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct EnumValue{
+    pub gtype: GType,
+    pub value: i32,
+}
+
+impl EnumValue{
+    pub fn new(gtype: GType, value: i32) -> EnumValue{
+        EnumValue{ gtype: gtype, value: value }
+    }
+
+    /// Returns the nick of the enum value, as used in the string
+    /// representation of caps and properties
+    pub fn nick(&self) -> Option<String>{
+        unsafe{
+            let class = g_type_class_ref(self.gtype) as *mut GEnumClass;
+            let enum_value = g_enum_get_value(class, self.value);
+            let nick = if enum_value != ptr::null_mut(){
+                Some(from_c_str!((*enum_value).value_nick).to_string())
+            }else{
+                None
+            };
+            g_type_class_unref(class as gpointer);
+            nick
+        }
+    }
+}
+
+impl FromGValue for EnumValue{
+    fn from_gvalue(value: &GValue) -> Option<EnumValue>{
+        if holds(value, G_TYPE_ENUM){
+            unsafe{ Some(EnumValue{ gtype: value.g_type, value: g_value_get_enum(value) }) }
+        }else{
+            None
+        }
+    }
+}
+
+impl ToGValue for EnumValue{
+    fn to_gvalue(&self) -> GValue{
+        let mut value = new_gvalue(self.gtype);
+        unsafe{ g_value_set_enum(&mut value, self.value) };
+        value
+    }
+}
+
+/// A value of a registered flags type
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct FlagsValue{
+    pub gtype: GType,
+    pub value: u32,
+}
+
+impl FlagsValue{
+    pub fn new(gtype: GType, value: u32) -> FlagsValue{
+        FlagsValue{ gtype: gtype, value: value }
+    }
+
+    pub fn contains(&self, flags: u32) -> bool{
+        self.value & flags == flags
+    }
+
+    /// Returns the nicks of the flags that are set
+    pub fn nicks(&self) -> Vec<String>{
+        unsafe{
+            let class = g_type_class_ref(self.gtype) as *mut GFlagsClass;
+            let mut nicks = vec![];
+            let mut remaining = self.value;
+            while remaining != 0{
+                let flags_value = g_flags_get_first_value(class, remaining);
+                if flags_value == ptr::null_mut() || (*flags_value).value == 0{
+                    break;
+                }
+                nicks.push(from_c_str!((*flags_value).value_nick).to_string());
+                remaining &= !(*flags_value).value;
+            }
+            g_type_class_unref(class as gpointer);
+            nicks
+        }
+    }
+}
+
+impl FromGValue for FlagsValue{
+    fn from_gvalue(value: &GValue) -> Option<FlagsValue>{
+        if holds(value, G_TYPE_FLAGS){
+            unsafe{ Some(FlagsValue{ gtype: value.g_type, value: g_value_get_flags(value) }) }
+        }else{
+            None
+        }
+    }
+}
+
+impl ToGValue for FlagsValue{
+    fn to_gvalue(&self) -> GValue{
+        let mut value = new_gvalue(self.gtype);
+        unsafe{ g_value_set_flags(&mut value, self.value) };
+        value
+    }
+}